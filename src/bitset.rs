@@ -0,0 +1,136 @@
+use crate::NonMaxUsize;
+
+/// A fixed-capacity set of [`NonMaxUsize`] keys, stored as a bitset.
+///
+/// The set is backed by `CAP` words of 64 bits each, so it can hold keys from
+/// zero up to, but not including, [`capacity`](Self::capacity).
+///
+/// Requires the `bitset` feature, which needs Rust 1.51 or newer for const
+/// generics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonMaxBitSet<const CAP: usize> {
+    words: [u64; CAP],
+}
+
+impl<const CAP: usize> NonMaxBitSet<CAP> {
+    /// Creates a new, empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { words: [0; CAP] }
+    }
+
+    /// Returns the number of distinct keys that the set can hold. Every key
+    /// stored in the set is less than this value.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP * 64
+    }
+
+    /// Adds a key to the set, returning `true` if it was not already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not less than [`capacity`](Self::capacity).
+    pub fn insert(&mut self, key: NonMaxUsize) -> bool {
+        let (word, mask) = Self::locate(key).expect("key out of range for NonMaxBitSet");
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Returns `true` if the set contains the key. Keys outside of the set's
+    /// capacity are never contained.
+    pub fn contains(&self, key: NonMaxUsize) -> bool {
+        match Self::locate(key) {
+            Some((word, mask)) => self.words[word] & mask != 0,
+            None => false,
+        }
+    }
+
+    /// Removes a key from the set, returning `true` if it was present.
+    pub fn remove(&mut self, key: NonMaxUsize) -> bool {
+        match Self::locate(key) {
+            Some((word, mask)) => {
+                let removed = self.words[word] & mask != 0;
+                self.words[word] &= !mask;
+                removed
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the word index and bit mask for a key, or `None` if the key is
+    /// out of range.
+    fn locate(key: NonMaxUsize) -> Option<(usize, u64)> {
+        let key = key.get();
+        let word = key / 64;
+        if word < CAP {
+            Some((word, 1 << (key % 64)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const CAP: usize> Default for NonMaxBitSet<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> core::fmt::Debug for NonMaxBitSet<CAP> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let keys = (0..self.capacity())
+            .filter_map(NonMaxUsize::new)
+            .filter(|&key| self.contains(key));
+        f.debug_set().entries(keys).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(value: usize) -> NonMaxUsize {
+        NonMaxUsize::new(value).unwrap()
+    }
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = NonMaxBitSet::<2>::new();
+        assert_eq!(set.capacity(), 128);
+        assert!(!set.contains(key(5)));
+
+        assert!(set.insert(key(5)));
+        assert!(!set.insert(key(5)));
+        assert!(set.contains(key(5)));
+        assert!(!set.contains(key(4)));
+        assert!(!set.contains(key(69)));
+
+        assert!(set.remove(key(5)));
+        assert!(!set.remove(key(5)));
+        assert!(!set.contains(key(5)));
+        assert_eq!(set, NonMaxBitSet::default());
+    }
+
+    #[test]
+    fn near_capacity() {
+        let mut set = NonMaxBitSet::<2>::new();
+        assert!(set.insert(key(63)));
+        assert!(set.insert(key(64)));
+        assert!(set.insert(key(127)));
+        assert!(set.contains(key(63)));
+        assert!(set.contains(key(64)));
+        assert!(set.contains(key(127)));
+
+        assert!(!set.contains(key(128)));
+        assert!(!set.contains(NonMaxUsize::MAX));
+        assert!(!set.remove(key(128)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_range() {
+        NonMaxBitSet::<2>::new().insert(key(128));
+    }
+}
@@ -0,0 +1,67 @@
+//! Entry points for fuzz targets, which check the crate's invariants on
+//! arbitrary input and panic if any of them don't hold.
+//!
+//! Requires the `fuzz` feature. These functions are meant to be called from
+//! fuzz targets like those used by `cargo fuzz`:
+//!
+//! ```ignore
+//! fuzz_target!(|data: &[u8]| nonmax::fuzz::fuzz_roundtrip_u32(data));
+//! ```
+
+use crate::NonMaxU32;
+
+/// Reads up to two little-endian `u32`s from `bytes` and checks that
+/// [`NonMaxU32`] construction, conversion, and arithmetic agree with `u32`.
+///
+/// Inputs shorter than four bytes are ignored.
+///
+/// # Panics
+///
+/// Panics if any invariant doesn't hold.
+pub fn fuzz_roundtrip_u32(bytes: &[u8]) {
+    let mut words = bytes.chunks_exact(4).map(|chunk| {
+        let mut word = [0; 4];
+        word.copy_from_slice(chunk);
+        u32::from_le_bytes(word)
+    });
+
+    let value = match words.next() {
+        Some(value) => value,
+        None => return,
+    };
+
+    let nonmax = NonMaxU32::new(value);
+    assert_eq!(nonmax.is_none(), value == u32::MAX);
+    assert_eq!(NonMaxU32::option_into_ffi(nonmax), value);
+    assert_eq!(NonMaxU32::option_from_ffi(value), nonmax);
+
+    let nonmax = match nonmax {
+        Some(nonmax) => nonmax,
+        None => return,
+    };
+    assert_eq!(nonmax.get(), value);
+    assert_eq!(NonMaxU32::new(nonmax.get()), Some(nonmax));
+
+    if let Some(rhs) = words.next().and_then(NonMaxU32::new) {
+        let expected = value.checked_add(rhs.get()).filter(|&sum| sum != u32::MAX);
+        assert_eq!(nonmax.checked_add(rhs).map(|sum| sum.get()), expected);
+        assert_eq!(nonmax.cmp(&rhs), value.cmp(&rhs.get()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roundtrip_u32() {
+        fuzz_roundtrip_u32(&[]);
+        fuzz_roundtrip_u32(&[1, 2, 3]);
+        fuzz_roundtrip_u32(&[0, 0, 0, 0]);
+        fuzz_roundtrip_u32(&[0xff, 0xff, 0xff, 0xff, 1, 0, 0, 0]);
+        fuzz_roundtrip_u32(&[0xfe, 0xff, 0xff, 0xff, 1, 0, 0, 0]);
+        fuzz_roundtrip_u32(&[0xfd, 0xff, 0xff, 0xff, 1, 0, 0, 0]);
+        fuzz_roundtrip_u32(&[10, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+        fuzz_roundtrip_u32(&[10, 0, 0, 0, 20, 0, 0, 0, 30]);
+    }
+}
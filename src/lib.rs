@@ -43,8 +43,21 @@ assert_eq!(oops, None);
 ## Features
 
 * `std` (default): implements [`std::error::Error`] for [`ParseIntError`] and
-[`TryFromIntError`]. Disable this feature for
-[`#![no_std]`](https://rust-embedded.github.io/book/intro/no-std.html) support.
+  [`TryFromIntError`]. Disable this feature for
+  [`#![no_std]`](https://rust-embedded.github.io/book/intro/no-std.html) support.
+
+* `arbitrary`: implements the `Arbitrary` trait from [`arbitrary`](https://crates.io/crates/arbitrary),
+  generating values directly from the valid range of each type.
+
+* `bitset`: provides `NonMaxBitSet`, a fixed-capacity set of `NonMaxUsize`
+  keys. It uses const generics, so it requires Rust 1.51 or newer.
+
+* `fuzz`: provides the `fuzz` module, with entry points that check the
+  crate's invariants for use in fuzz targets.
+
+* `itoa`: formats values with [`itoa`](https://crates.io/crates/itoa) in their
+  `Display` implementations, which is faster than the standard library. The
+  output is the same.
 
 ## Minimum Supported Rust Version (MSRV)
 
@@ -56,6 +69,15 @@ will only require minor version bumps, but will need significant justification.
 #![forbid(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "bitset")]
+mod bitset;
+
+#[cfg(feature = "bitset")]
+pub use bitset::NonMaxBitSet;
+
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
 /// An error type returned when a checked integral type conversion fails (mimics [std::num::TryFromIntError])
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TryFromIntError(());
@@ -81,6 +103,20 @@ impl From<core::convert::Infallible> for TryFromIntError {
     }
 }
 
+/// An error type returned when a float can't be converted to a non-max float
+/// because it's the largest finite value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromFloatError(());
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromFloatError {}
+
+impl core::fmt::Display for TryFromFloatError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        "float is the largest finite value of its type".fmt(fmt)
+    }
+}
+
 /// An error type returned when an integer cannot be parsed (mimics [std::num::ParseIntError])
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ParseIntError(());
@@ -100,6 +136,269 @@ impl From<core::num::ParseIntError> for ParseIntError {
     }
 }
 
+/// The reason an integer could not be parsed, returned by `parse_detailed`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseDetail {
+    /// The input was empty.
+    Empty,
+
+    /// The input contained a character that isn't a digit, or a sign with no
+    /// digits after it.
+    InvalidDigit,
+
+    /// The input was a number too large or too small for the primitive type.
+    Overflow,
+
+    /// The input was the maximum value of the primitive type, which can't be
+    /// stored in a non-max type.
+    EqualsMax,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDetail {}
+
+impl core::fmt::Display for ParseDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large or too small to fit in target type",
+            Self::EqualsMax => "number is the maximum value of its type",
+        }
+        .fmt(fmt)
+    }
+}
+
+/// Conversion that returns `None` if the value can't be represented by the
+/// target type.
+///
+/// This mirrors [`TryFrom`](core::convert::TryFrom), but returns an `Option`
+/// instead of a `Result`. It's implemented for conversions from every
+/// primitive integer and every non-max type into every non-max type.
+pub trait CheckedFrom<T>: Sized {
+    /// Converts the value, returning `None` if it's out of range for the
+    /// target type or equal to its maximum value.
+    fn checked_from(value: T) -> Option<Self>;
+}
+
+/// Bounds and common constants of a non-max type, for use in generic code.
+///
+/// This is implemented for every non-max type, with the same values as the
+/// type's associated constants.
+pub trait NonMaxBounds: Sized {
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type, which is one less than the maximum of
+    /// the underlying primitive.
+    const MAX: Self;
+
+    /// The value zero (0).
+    const ZERO: Self;
+
+    /// The value one (1).
+    const ONE: Self;
+}
+
+/// A non-max integer type, for writing code that's generic over all of them.
+///
+/// This trait is sealed and can't be implemented outside of this crate, which
+/// allows adding methods to it without breaking changes.
+pub trait NonMaxValue: sealed::Sealed + Sized {
+    /// The primitive integer type of the value.
+    type Primitive;
+
+    /// Creates a new non-max if the given value is not the maximum value.
+    fn new(value: Self::Primitive) -> Option<Self>;
+
+    /// Returns the value as a primitive type.
+    fn get(&self) -> Self::Primitive;
+}
+
+/// Extension methods on `Option<NonMax*>` for converting to and from a
+/// primitive, using the maximum value to represent `None`.
+///
+/// These behave like `option_into_ffi` and `option_from_ffi` on each non-max
+/// type, but allow method syntax and generic code. This trait is sealed and
+/// can't be implemented outside of this crate.
+pub trait OptionNonMaxExt: sealed::Sealed + Copy {
+    /// The primitive integer type that the optional value is stored as.
+    type Primitive;
+
+    /// Converts the optional value into a primitive, using the maximum value
+    /// to represent `None`.
+    fn to_sentinel(self) -> Self::Primitive;
+
+    /// Converts a primitive into an optional value, treating the maximum
+    /// value as `None`.
+    fn from_sentinel(value: Self::Primitive) -> Self;
+}
+
+/// A byte order, used when converting values to bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Big-endian, with the most significant byte first.
+    Big,
+
+    /// Little-endian, with the least significant byte first.
+    Little,
+
+    /// The native byte order of the target platform.
+    Native,
+}
+
+/// A wrapper around a non-max value whose [`Default`] is one instead of zero.
+///
+/// Non-max types default to zero, like the primitive types. When values are
+/// created with `Default`, like with `or_default` on map entries, wrapping them
+/// in `DefaultOne` makes them start from one instead. Other defaults can be
+/// handled with the same pattern: a newtype with its own `Default` impl.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct DefaultOne<T>(pub T);
+
+impl<T: NonMaxBounds> Default for DefaultOne<T> {
+    fn default() -> Self {
+        Self(T::ONE)
+    }
+}
+
+mod sealed {
+    /// Prevents traits from being implemented outside of this crate.
+    pub trait Sealed {}
+}
+
+/// Serializes a slice of optional non-max values as a sequence of primitives,
+/// using the maximum value to represent `None`.
+///
+/// This avoids the per-element overhead that serializing `Option` usually
+/// has. Use it on a field with `#[serde(with = "nonmax::option_slice")]`, or
+/// on its own with `#[serde(serialize_with = "nonmax::serialize_option_slice")]`.
+#[cfg(feature = "serde")]
+pub fn serialize_option_slice<T, S>(values: &[Option<T>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    Option<T>: OptionNonMaxExt,
+    <Option<T> as OptionNonMaxExt>::Primitive: serde::Serialize,
+    S: serde::Serializer,
+{
+    serializer.collect_seq(values.iter().map(|value| value.to_sentinel()))
+}
+
+/// Deserializes a sequence of primitives into optional non-max values, treating
+/// the maximum value as `None`.
+///
+/// This is the counterpart to [`serialize_option_slice`]. Use it on a field
+/// with `#[serde(with = "nonmax::option_slice")]`, or on its own with
+/// `#[serde(deserialize_with = "nonmax::deserialize_option_slice")]`.
+#[cfg(all(feature = "serde", feature = "std"))]
+pub fn deserialize_option_slice<'de, T, D>(deserializer: D) -> Result<Vec<Option<T>>, D::Error>
+where
+    Option<T>: OptionNonMaxExt,
+    <Option<T> as OptionNonMaxExt>::Primitive: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    struct OptionSliceVisitor<T>(core::marker::PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for OptionSliceVisitor<T>
+    where
+        Option<T>: OptionNonMaxExt,
+        <Option<T> as OptionNonMaxExt>::Primitive: serde::Deserialize<'de>,
+    {
+        type Value = Vec<Option<T>>;
+
+        fn expecting(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            fmt.write_str("a sequence of integers")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            // Don't trust the size hint too much, since it comes from the input.
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(value) = seq.next_element()? {
+                values.push(Option::<T>::from_sentinel(value));
+            }
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_seq(OptionSliceVisitor(core::marker::PhantomData))
+}
+
+/// [`serialize_option_slice`] and [`deserialize_option_slice`] as a module, for
+/// use with `#[serde(with = "nonmax::option_slice")]` on fields of type
+/// `Vec<Option<NonMax*>>`.
+#[cfg(feature = "serde")]
+pub mod option_slice {
+    pub use crate::serialize_option_slice as serialize;
+
+    #[cfg(feature = "std")]
+    pub use crate::deserialize_option_slice as deserialize;
+}
+
+/// An error type returned when a buffer is too small to hold a formatted value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall(());
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        "buffer too small for formatted integer".fmt(fmt)
+    }
+}
+
+/// Returns the number of digits needed to write `magnitude` in `radix`.
+const fn radix_len(mut magnitude: u128, radix: u32) -> usize {
+    // Invalid radixes are rejected when writing, but shouldn't cause a
+    // division by zero or an infinite loop here.
+    let radix = if radix < 2 { 2 } else { radix as u128 };
+
+    let mut len = 1;
+    while magnitude >= radix {
+        magnitude /= radix;
+        len += 1;
+    }
+    len
+}
+
+/// Writes a number with the given sign and magnitude to the start of `buf`
+/// using lowercase digits, returning the written part of the buffer.
+fn write_radix(
+    negative: bool,
+    mut magnitude: u128,
+    radix: u32,
+    buf: &mut [u8],
+) -> Result<&str, BufferTooSmall> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range from 2 to 36"
+    );
+
+    let sign_len = negative as usize;
+    let len = sign_len + radix_len(magnitude, radix);
+    let buf = buf.get_mut(..len).ok_or(BufferTooSmall(()))?;
+
+    for byte in buf[sign_len..].iter_mut().rev() {
+        let digit = (magnitude % radix as u128) as u8;
+        *byte = if digit < 10 {
+            b'0' + digit
+        } else {
+            b'a' + digit - 10
+        };
+        magnitude /= radix as u128;
+    }
+    if negative {
+        buf[0] = b'-';
+    }
+
+    // Safety: only ASCII digits and signs were written to the buffer
+    Ok(unsafe { core::str::from_utf8_unchecked(buf) })
+}
+
 // error[E0658]: the `!` type is experimental
 // https://github.com/rust-lang/rust/issues/35121
 // impl From<!> for TryFromIntError { ... }
@@ -121,7 +420,7 @@ macro_rules! impl_nonmax_fmt {
 macro_rules! nonmax {
     ( common, $nonmax: ident, $non_zero: ident, $primitive: ident ) => {
         /// An integer that is known not to equal its maximum value.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #[derive(Clone, Copy, PartialEq, Eq)]
         #[repr(transparent)]
         pub struct $nonmax(core::num::$non_zero);
 
@@ -136,6 +435,23 @@ macro_rules! nonmax {
                 }
             }
 
+            /// Creates a new non-max if the given value is not the maximum
+            /// value, without comparing against the maximum.
+            ///
+            /// This returns the same result as [`new`](Self::new), but builds
+            /// the `Option` by reinterpreting the encoded value directly, using
+            /// the guarantee that `None` is represented by zero for `Option`s
+            /// of transparent `NonZero*` wrappers.
+            #[inline]
+            pub fn new_branchless(value: $primitive) -> Option<Self> {
+                // Safety: `Self` is a transparent wrapper around a `NonZero*`
+                // type, so `Option<Self>` has the same layout as the primitive,
+                // with `None` represented by zero and any other value by
+                // `Some`. The encoded value is zero exactly when `value` is
+                // the maximum.
+                unsafe { core::mem::transmute::<$primitive, Option<Self>>(value ^ $primitive::MAX) }
+            }
+
             /// Creates a new non-max without checking the value.
             ///
             /// # Safety
@@ -154,6 +470,19 @@ macro_rules! nonmax {
                 self.0.get() ^ $primitive::MAX
             }
 
+            /// Returns the value behind a reference as a primitive type.
+            ///
+            /// This is equivalent to [`get`](Self::get), but can be used as a
+            /// plain function in constant expressions, like when reading
+            /// values out of a constant array.
+            #[inline]
+            pub const fn get_ref(this: &Self) -> $primitive {
+                this.get()
+            }
+
+            /// Gets non-max with minimum possible value (which is minimum of the underlying primitive)
+            pub const MIN: $nonmax = unsafe { Self::new_unchecked($primitive::MIN) };
+
             /// Gets non-max with the value zero (0)
             pub const ZERO: $nonmax = unsafe { Self::new_unchecked(0) };
 
@@ -162,6 +491,342 @@ macro_rules! nonmax {
 
             /// Gets non-max with maximum possible value (which is maximum of the underlying primitive minus one)
             pub const MAX: $nonmax = unsafe { Self::new_unchecked($primitive::MAX - 1) };
+
+            /// Returns the smallest value of this type, which is the same as
+            /// [`MIN`](Self::MIN).
+            #[inline]
+            pub const fn min_value() -> Self {
+                Self::MIN
+            }
+
+            /// Returns the largest value of this type, which is the same as
+            /// [`MAX`](Self::MAX).
+            #[inline]
+            pub const fn max_value() -> Self {
+                Self::MAX
+            }
+
+            /// The size in bytes of this type's binary encoding, which is the
+            /// size of the underlying primitive.
+            pub const ENCODED_LEN: usize = core::mem::size_of::<$primitive>();
+
+            /// Returns the number of distinct values this type can hold, which
+            /// is one less than the number of values of the primitive type.
+            #[inline]
+            pub const fn cardinality() -> u128 {
+                ($primitive::MAX as u128).wrapping_sub($primitive::MIN as u128)
+            }
+
+            /// Returns the bitwise complement of the value as a primitive type.
+            ///
+            /// The result is a primitive because the complement of a non-max
+            /// value can be the maximum value.
+            #[inline]
+            pub const fn not_primitive(self) -> $primitive {
+                !self.get()
+            }
+
+            /// Reverses the byte order of the value, returning it as a primitive
+            /// type.
+            ///
+            /// The result is a primitive because reversing the bytes of a
+            /// non-max value can produce the maximum value.
+            #[inline]
+            pub const fn swap_bytes_primitive(self) -> $primitive {
+                self.get().swap_bytes()
+            }
+
+            /// Checked integer addition. Computes `self + rhs`, returning `None`
+            /// if overflow occurred or the result is the maximum value.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.get().checked_add(rhs.get()) {
+                    Some(value) => Self::new(value),
+                    None => None,
+                }
+            }
+
+            /// Returns the values directly before and after this one, or `None`
+            /// for a neighbor that is out of range.
+            ///
+            /// The predecessor of [`MIN`](Self::MIN) and the successor of
+            /// [`MAX`](Self::MAX) are `None`.
+            #[inline]
+            pub const fn neighbors(self) -> (Option<Self>, Option<Self>) {
+                let pred = match self.get().checked_sub(1) {
+                    // Safety: the predecessor of a non-max value is never max
+                    Some(value) => Some(unsafe { Self::new_unchecked(value) }),
+                    None => None,
+                };
+                (pred, self.checked_add(Self::ONE))
+            }
+
+            /// Checked integer remainder. Returns `None` if `rhs` is zero or if
+            /// the operation overflows, which happens for signed types when
+            /// dividing the minimum value by `-1`.
+            #[inline]
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.get().checked_rem(rhs.get()).and_then(Self::new)
+            }
+
+            /// Adds up the values in `slice`, returning `None` if the sum
+            /// overflows or reaches the maximum value at any point. The sum of
+            /// an empty slice is zero.
+            #[inline]
+            pub const fn checked_sum(slice: &[Self]) -> Option<Self> {
+                let mut sum = Self::ZERO;
+                let mut i = 0;
+                while i < slice.len() {
+                    sum = match sum.checked_add(slice[i]) {
+                        Some(sum) => sum,
+                        None => return None,
+                    };
+                    i += 1;
+                }
+                Some(sum)
+            }
+
+            /// Returns `true` if adding `rhs` would overflow or produce the
+            /// maximum value, meaning that [`checked_add`](Self::checked_add)
+            /// would return `None`.
+            #[inline]
+            pub const fn would_overflow_add(self, rhs: Self) -> bool {
+                match self.get().checked_add(rhs.get()) {
+                    Some(value) => value == $primitive::MAX,
+                    None => true,
+                }
+            }
+
+            /// Checked shift left. Returns `None` if `rhs` is larger than or
+            /// equal to the number of bits in the primitive type.
+            #[inline]
+            pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+                match self.get().checked_shl(rhs) {
+                    // Safety: shifting left by at least one bit clears the
+                    // lowest bit, which is always set in the maximum value, and
+                    // shifting by zero bits returns `self` unchanged.
+                    Some(value) => Some(unsafe { Self::new_unchecked(value) }),
+                    None => None,
+                }
+            }
+
+            /// Checked shift right. Returns `None` if `rhs` is larger than or
+            /// equal to the number of bits in the primitive type.
+            #[inline]
+            pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+                match self.get().checked_shr(rhs) {
+                    // Safety: shifting right by at least one bit halves
+                    // non-negative values and keeps negative values negative,
+                    // so the maximum value can't be reached, and shifting by
+                    // zero bits returns `self` unchanged.
+                    Some(value) => Some(unsafe { Self::new_unchecked(value) }),
+                    None => None,
+                }
+            }
+
+            /// Saturating addition with a primitive value. Computes
+            /// `self + rhs`, saturating at the numeric bounds of the non-max
+            /// type instead of overflowing.
+            #[inline]
+            pub const fn saturating_add_primitive(self, rhs: $primitive) -> Self {
+                let value = self.get().saturating_add(rhs);
+                if value == $primitive::MAX {
+                    Self::MAX
+                } else {
+                    // Safety: the value was just checked to not be the maximum
+                    unsafe { Self::new_unchecked(value) }
+                }
+            }
+
+            /// Returns `true` if the value is less than `rhs`. Unlike comparison
+            /// operators, this can be used in constant expressions.
+            #[inline]
+            pub const fn const_lt_primitive(self, rhs: $primitive) -> bool {
+                self.get() < rhs
+            }
+
+            /// Returns `true` if the value is equal to `rhs`. Unlike comparison
+            /// operators, this can be used in constant expressions.
+            #[inline]
+            pub const fn const_eq_primitive(self, rhs: $primitive) -> bool {
+                self.get() == rhs
+            }
+
+            /// Returns `true` if the value is greater than `rhs`. Unlike
+            /// comparison operators, this can be used in constant expressions.
+            #[inline]
+            pub const fn const_gt_primitive(self, rhs: $primitive) -> bool {
+                self.get() > rhs
+            }
+
+            /// Converts an optional non-max value into a primitive, using the
+            /// maximum value to represent `None`.
+            ///
+            /// This is useful when passing optional values across an FFI
+            /// boundary. It's the inverse of
+            /// [`option_from_ffi`](Self::option_from_ffi).
+            #[inline]
+            pub const fn option_into_ffi(value: Option<Self>) -> $primitive {
+                match value {
+                    Some(value) => value.get(),
+                    None => $primitive::MAX,
+                }
+            }
+
+            /// Converts a primitive into an optional non-max value, treating
+            /// the maximum value as `None`.
+            ///
+            /// This is the inverse of
+            /// [`option_into_ffi`](Self::option_into_ffi).
+            #[inline]
+            pub const fn option_from_ffi(value: $primitive) -> Option<Self> {
+                Self::new(value)
+            }
+
+            /// Clamps a primitive value into the range `min..=max`, returning a
+            /// non-max value. The maximum primitive value is clamped to `max`
+            /// like any other out-of-range value.
+            ///
+            /// `min` should be less than or equal to `max`. If it isn't, the
+            /// result is still a valid non-max value, but is otherwise
+            /// unspecified.
+            #[inline]
+            pub const fn clamp_primitive(value: $primitive, min: Self, max: Self) -> Self {
+                if value < min.get() {
+                    min
+                } else if value > max.get() {
+                    max
+                } else {
+                    // Safety: `value` is at most `max`, which is non-max
+                    unsafe { Self::new_unchecked(value) }
+                }
+            }
+
+            /// Parses a non-max value from ASCII bytes in the given radix,
+            /// failing if the parsed value is the maximum value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not in the range from 2 to 36.
+            pub fn from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseIntError> {
+                let src = core::str::from_utf8(src).map_err(|_| ParseIntError(()))?;
+                Self::new($primitive::from_str_radix(src, radix)?).ok_or(ParseIntError(()))
+            }
+
+            /// Parses a non-max value from a decimal string like
+            /// [`FromStr`](core::str::FromStr), but reports why parsing
+            /// failed.
+            pub fn parse_detailed(src: &str) -> Result<Self, ParseDetail> {
+                if src.is_empty() {
+                    return Err(ParseDetail::Empty);
+                }
+
+                match src.parse::<$primitive>() {
+                    Ok(value) => Self::new(value).ok_or(ParseDetail::EqualsMax),
+                    Err(_) => {
+                        // The primitive's error kind isn't available at our
+                        // MSRV, so tell overflow apart from invalid input by
+                        // checking whether the input is well-formed.
+                        let digits = match src.as_bytes()[0] {
+                            b'+' => &src[1..],
+                            b'-' if $primitive::MIN != 0 => &src[1..],
+                            _ => src,
+                        };
+                        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+                            Err(ParseDetail::Overflow)
+                        } else {
+                            Err(ParseDetail::InvalidDigit)
+                        }
+                    }
+                }
+            }
+
+            /// Decodes a stream of deltas into running values, starting from
+            /// `start`.
+            ///
+            /// Each item is the sum of `start` and every delta up to and
+            /// including the current one. Once the sum overflows or reaches the
+            /// maximum value, that item and every one after it is `None`.
+            pub fn delta_decode<I>(deltas: I, start: Self) -> impl Iterator<Item = Option<Self>>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                deltas.into_iter().scan(Some(start), |current, delta| {
+                    *current = current.and_then(|current| current.checked_add(delta));
+                    Some(*current)
+                })
+            }
+
+            /// Lazily converts primitive values into non-max values, yielding
+            /// an error for each value that is the maximum.
+            ///
+            /// This is the streaming counterpart to `try_collect`.
+            pub fn validated<I>(iter: I) -> impl Iterator<Item = Result<Self, TryFromIntError>>
+            where
+                I: IntoIterator<Item = $primitive>,
+            {
+                iter.into_iter()
+                    .map(|value| Self::new(value).ok_or(TryFromIntError(())))
+            }
+
+            /// Collects primitive values into a `Vec` of non-max values,
+            /// stopping at the first value that is the maximum.
+            #[cfg(feature = "std")]
+            pub fn try_collect<I>(iter: I) -> Result<Vec<Self>, TryFromIntError>
+            where
+                I: IntoIterator<Item = $primitive>,
+            {
+                iter.into_iter()
+                    .map(|value| Self::new(value).ok_or(TryFromIntError(())))
+                    .collect()
+            }
+
+            /// Returns the bytes of the primitive value in the given byte
+            /// order.
+            #[cfg(feature = "std")]
+            pub fn to_bytes_vec(self, endian: Endian) -> Vec<u8> {
+                match endian {
+                    Endian::Big => self.get().to_be_bytes().to_vec(),
+                    Endian::Little => self.get().to_le_bytes().to_vec(),
+                    Endian::Native => self.get().to_ne_bytes().to_vec(),
+                }
+            }
+        }
+
+        impl sealed::Sealed for $nonmax {}
+        impl sealed::Sealed for Option<$nonmax> {}
+
+        impl OptionNonMaxExt for Option<$nonmax> {
+            type Primitive = $primitive;
+
+            fn to_sentinel(self) -> $primitive {
+                $nonmax::option_into_ffi(self)
+            }
+
+            fn from_sentinel(value: $primitive) -> Self {
+                $nonmax::option_from_ffi(value)
+            }
+        }
+
+        impl NonMaxValue for $nonmax {
+            type Primitive = $primitive;
+
+            #[inline]
+            fn new(value: $primitive) -> Option<Self> {
+                Self::new(value)
+            }
+
+            #[inline]
+            fn get(&self) -> $primitive {
+                self.get()
+            }
+        }
+
+        impl NonMaxBounds for $nonmax {
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
         }
 
         impl Default for $nonmax {
@@ -183,6 +848,37 @@ macro_rules! nonmax {
             }
         }
 
+        impl core::convert::TryFrom<&$primitive> for $nonmax {
+            type Error = TryFromIntError;
+            fn try_from(value: &$primitive) -> Result<Self, Self::Error> {
+                Self::try_from(*value)
+            }
+        }
+
+        impl From<$nonmax> for core::num::Wrapping<$primitive> {
+            fn from(value: $nonmax) -> Self {
+                core::num::Wrapping(value.get())
+            }
+        }
+
+        impl core::convert::TryFrom<core::num::Wrapping<$primitive>> for $nonmax {
+            type Error = TryFromIntError;
+            fn try_from(value: core::num::Wrapping<$primitive>) -> Result<Self, Self::Error> {
+                Self::try_from(value.0)
+            }
+        }
+
+        /// Interprets the bytes in native byte order, failing if they
+        /// represent the maximum value.
+        impl core::convert::TryFrom<[u8; core::mem::size_of::<$primitive>()]> for $nonmax {
+            type Error = TryFromIntError;
+            fn try_from(
+                bytes: [u8; core::mem::size_of::<$primitive>()],
+            ) -> Result<Self, Self::Error> {
+                Self::try_from($primitive::from_ne_bytes(bytes))
+            }
+        }
+
         impl core::str::FromStr for $nonmax {
             type Err = ParseIntError;
             fn from_str(value: &str) -> Result<Self, Self::Err> {
@@ -201,6 +897,14 @@ macro_rules! nonmax {
             }
         }
 
+        // Hash the logical value rather than the encoded one so that hashes
+        // don't depend on the representation and match the primitive's.
+        impl core::hash::Hash for $nonmax {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
         // NonZero can implement BitOr (will never 0 a nonzero value) but not BitAnd.
         // NonMax can implement BitAnd but not BitOr, with some caveats for signed values:
         // -1 (11...11) & max (01...11) can result in signed max (01...11), so both operands must be nonmax for signed variants
@@ -220,34 +924,122 @@ macro_rules! nonmax {
             }
         }
 
-        // https://doc.rust-lang.org/1.47.0/src/core/num/mod.rs.html#173-175
-        impl_nonmax_fmt! {
-            (Debug, Display, Binary, Octal, LowerHex, UpperHex) for $nonmax
-        }
+        impl core::ops::Shl<u32> for $nonmax {
+            type Output = $nonmax;
 
-        #[cfg(feature = "serde")]
-        impl serde::Serialize for $nonmax {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer,
-            {
-                self.get().serialize(serializer)
+            /// Shifts the value left by `rhs` bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is larger than or equal to the number of bits in
+            /// the primitive type. The result can never be the maximum value.
+            fn shl(self, rhs: u32) -> Self::Output {
+                self.checked_shl(rhs)
+                    .expect("attempt to shift left with overflow")
             }
         }
 
-        #[cfg(feature = "serde")]
-        impl<'de> serde::Deserialize<'de> for $nonmax {
-            fn deserialize<D>(deserializer: D) -> Result<$nonmax, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                let value = $primitive::deserialize(deserializer)?;
-                use core::convert::TryFrom;
-                Self::try_from(value).map_err(serde::de::Error::custom)
+        impl core::ops::Shr<u32> for $nonmax {
+            type Output = $nonmax;
+
+            /// Shifts the value right by `rhs` bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is larger than or equal to the number of bits in
+            /// the primitive type. The result can never be the maximum value.
+            fn shr(self, rhs: u32) -> Self::Output {
+                self.checked_shr(rhs)
+                    .expect("attempt to shift right with overflow")
             }
         }
 
-        #[cfg(test)]
+        impl core::ops::ShlAssign<u32> for $nonmax {
+            /// Shifts the value left by `rhs` bits in place.
+            ///
+            /// # Panics
+            ///
+            /// Panics under the same conditions as
+            /// [`Shl`](core::ops::Shl::shl).
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl core::ops::ShrAssign<u32> for $nonmax {
+            /// Shifts the value right by `rhs` bits in place.
+            ///
+            /// # Panics
+            ///
+            /// Panics under the same conditions as
+            /// [`Shr`](core::ops::Shr::shr).
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+
+        // https://doc.rust-lang.org/1.47.0/src/core/num/mod.rs.html#173-175
+        impl_nonmax_fmt! {
+            (Debug, Binary, Octal, LowerHex, UpperHex) for $nonmax
+        }
+
+        #[cfg(not(feature = "itoa"))]
+        impl_nonmax_fmt! {
+            (Display) for $nonmax
+        }
+
+        #[cfg(feature = "itoa")]
+        impl core::fmt::Display for $nonmax {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut buffer = itoa::Buffer::new();
+                let formatted = buffer.format(self.get());
+
+                // `pad_integral` writes the sign itself and handles flags like
+                // width, fill, and `+`, the same way the primitive does.
+                let digits = formatted.trim_start_matches('-');
+                f.pad_integral(digits.len() == formatted.len(), "", digits)
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $nonmax {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                // Generating directly from the valid range means no input is
+                // ever rejected, unlike generating a primitive and calling `new`.
+                let value = u.int_in_range($primitive::MIN..=$primitive::MAX - 1)?;
+
+                // Safety: the range excludes the maximum value
+                Ok(unsafe { Self::new_unchecked(value) })
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$primitive as arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $nonmax {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.get().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $nonmax {
+            fn deserialize<D>(deserializer: D) -> Result<$nonmax, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = $primitive::deserialize(deserializer)?;
+                use core::convert::TryFrom;
+                Self::try_from(value).map_err(serde::de::Error::custom)
+            }
+        }
+
+        #[cfg(test)]
         mod $primitive {
             use super::*;
 
@@ -279,6 +1071,10 @@ macro_rules! nonmax {
                 assert_eq!(zero, 0);
 
                 $nonmax::try_from($primitive::MAX).unwrap_err();
+
+                let zero = $nonmax::try_from(&(0 as $primitive)).unwrap();
+                assert_eq!(zero.get(), 0);
+                $nonmax::try_from(&$primitive::MAX).unwrap_err();
             }
 
             #[test]
@@ -300,6 +1096,26 @@ macro_rules! nonmax {
                 assert_eq!(zero.get(), 0);
                 assert_eq!(one.get(), 1);
                 assert_eq!(max.get(), $primitive::MAX - 1);
+                assert_eq!($nonmax::MIN.get(), $primitive::MIN);
+            }
+
+            #[test]
+            fn bounds() {
+                assert_eq!(<$nonmax as NonMaxBounds>::MIN, $nonmax::MIN);
+                assert_eq!(<$nonmax as NonMaxBounds>::MAX, $nonmax::MAX);
+                assert_eq!(<$nonmax as NonMaxBounds>::ZERO, $nonmax::ZERO);
+                assert_eq!(<$nonmax as NonMaxBounds>::ONE, $nonmax::ONE);
+            }
+
+            #[test]
+            fn cardinality() {
+                let bits = size_of::<$primitive>() * 8;
+                let expected = if bits < 128 {
+                    (1u128 << bits) - 1
+                } else {
+                    u128::MAX
+                };
+                assert_eq!($nonmax::cardinality(), expected);
             }
 
             #[test]
@@ -339,17 +1155,601 @@ macro_rules! nonmax {
                     assert_eq!(nonmax_value, decoded);
                 }
             }
+
+            #[test]
+            #[cfg(feature = "std")] // Vec
+            fn try_collect() {
+                let values = vec![0, 19, $primitive::MAX - 1];
+                let collected = $nonmax::try_collect(values.iter().copied()).unwrap();
+                let collected: Vec<$primitive> = collected.iter().map($nonmax::get).collect();
+                assert_eq!(collected, values);
+
+                let values = vec![0, $primitive::MAX, 19];
+                $nonmax::try_collect(values).unwrap_err();
+            }
+
+            #[test]
+            fn saturating_add_primitive() {
+                let one = $nonmax::ONE;
+                assert_eq!(one.saturating_add_primitive(1).get(), 2);
+                assert_eq!(
+                    one.saturating_add_primitive($primitive::MAX - 2),
+                    $nonmax::MAX
+                );
+                assert_eq!(
+                    one.saturating_add_primitive($primitive::MAX - 1),
+                    $nonmax::MAX
+                );
+                assert_eq!(one.saturating_add_primitive($primitive::MAX), $nonmax::MAX);
+                assert_eq!($nonmax::MAX.saturating_add_primitive(0), $nonmax::MAX);
+            }
+
+            #[test]
+            #[cfg(feature = "arbitrary")]
+            fn arbitrary() {
+                use arbitrary::{Arbitrary, Unstructured};
+
+                let bytes = [0xff; 64];
+                let mut u = Unstructured::new(&bytes);
+                while !u.is_empty() {
+                    let value = $nonmax::arbitrary(&mut u).unwrap();
+                    assert_ne!(value.get(), $primitive::MAX);
+                }
+            }
+
+            #[test]
+            fn const_cmp_primitive() {
+                let one = $nonmax::ONE;
+                assert!(one.const_lt_primitive(2));
+                assert!(!one.const_lt_primitive(1));
+                assert!(one.const_eq_primitive(1));
+                assert!(!one.const_eq_primitive(0));
+                assert!(one.const_gt_primitive(0));
+                assert!(!one.const_gt_primitive(1));
+                assert!($nonmax::MAX.const_lt_primitive($primitive::MAX));
+            }
+
+            #[test]
+            fn option_ffi() {
+                assert_eq!($nonmax::option_into_ffi(None), $primitive::MAX);
+                assert_eq!($nonmax::option_from_ffi($primitive::MAX), None);
+
+                for value in [0, 19, $primitive::MAX - 1].iter().copied() {
+                    let nonmax = $nonmax::option_from_ffi(value);
+                    assert_eq!(nonmax, $nonmax::new(value));
+                    assert_eq!($nonmax::option_into_ffi(nonmax), value);
+                }
+            }
+
+            #[test]
+            fn clamp_primitive() {
+                let (min, max) = ($nonmax::ZERO, $nonmax::MAX);
+                assert_eq!(
+                    $nonmax::clamp_primitive($primitive::MAX, min, max),
+                    $nonmax::MAX
+                );
+                assert_eq!($nonmax::clamp_primitive(19, min, max).get(), 19);
+
+                let (one, two) = ($nonmax::ONE, $nonmax::new(2).unwrap());
+                assert_eq!($nonmax::clamp_primitive(0, one, two), one);
+                assert_eq!($nonmax::clamp_primitive(1, one, two), one);
+                assert_eq!($nonmax::clamp_primitive(2, one, two), two);
+                assert_eq!($nonmax::clamp_primitive(3, one, two), two);
+                assert_eq!($nonmax::clamp_primitive($primitive::MAX, one, two), two);
+            }
+
+            #[test]
+            #[cfg(all(feature = "serde", feature = "std"))]
+            fn serde_option_slice() {
+                #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+                struct Sparse {
+                    #[serde(with = "option_slice")]
+                    values: Vec<Option<$nonmax>>,
+                }
+
+                let sparse = Sparse {
+                    values: vec![
+                        $nonmax::new(0),
+                        None,
+                        $nonmax::new($primitive::MAX - 1),
+                        None,
+                    ],
+                };
+                let encoded: Vec<u8> = bincode::serialize(&sparse).unwrap();
+                let len = sparse.values.len();
+                assert_eq!(
+                    encoded.len(),
+                    size_of::<u64>() + len * size_of::<$primitive>()
+                );
+
+                let decoded: Sparse = bincode::deserialize(&encoded[..]).unwrap();
+                assert_eq!(decoded.values, sparse.values);
+            }
+
+            #[test]
+            #[cfg(feature = "std")] // format!
+            fn from_ascii_radix() {
+                for value in [0, 19, $primitive::MAX - 1].iter().copied() {
+                    let string = format!("{:x}", value);
+                    let nonmax = $nonmax::from_ascii_radix(string.as_bytes(), 16).unwrap();
+                    assert_eq!(nonmax.get(), value);
+                }
+
+                let max = format!("{:x}", $primitive::MAX);
+                $nonmax::from_ascii_radix(max.as_bytes(), 16).unwrap_err();
+            }
+
+            #[test]
+            fn not_primitive() {
+                for value in [0, 19, $primitive::MAX - 1].iter().copied() {
+                    assert_eq!($nonmax::new(value).unwrap().not_primitive(), !value);
+                }
+            }
+
+            #[test]
+            fn encoded_len() {
+                assert_eq!($nonmax::ENCODED_LEN, size_of::<$primitive>());
+            }
+
+            #[test]
+            fn default_one() {
+                assert_eq!(DefaultOne::<$nonmax>::default().0, $nonmax::ONE);
+                assert_eq!($nonmax::default(), $nonmax::ZERO);
+            }
+
+            #[test]
+            fn swap_bytes_primitive() {
+                for value in [0, 19, $primitive::MAX - 1].iter().copied() {
+                    let nonmax = $nonmax::new(value).unwrap();
+                    assert_eq!(nonmax.swap_bytes_primitive(), value.swap_bytes());
+                }
+            }
+
+            #[test]
+            fn nonmax_value() {
+                fn roundtrip<T: NonMaxValue>(value: T::Primitive) -> Option<T::Primitive> {
+                    T::new(value).map(|nonmax| nonmax.get())
+                }
+
+                for value in [0, 19, $primitive::MAX - 1].iter().copied() {
+                    assert_eq!(roundtrip::<$nonmax>(value), Some(value));
+                }
+                assert_eq!(roundtrip::<$nonmax>($primitive::MAX), None);
+            }
+
+            #[test]
+            fn min_max_value() {
+                const MIN: $nonmax = $nonmax::min_value();
+                const MAX: $nonmax = $nonmax::max_value();
+                assert_eq!(MIN, $nonmax::MIN);
+                assert_eq!(MAX, $nonmax::MAX);
+
+                let make: fn() -> $nonmax = $nonmax::max_value;
+                assert_eq!(make().get(), $primitive::MAX - 1);
+            }
+
+            #[test]
+            fn write_radix() {
+                let mut buf = [0; 130];
+                assert_eq!($nonmax::ZERO.write_radix(16, &mut buf), Ok("0"));
+                assert_eq!(
+                    $nonmax::new(19).unwrap().write_radix(16, &mut buf),
+                    Ok("13")
+                );
+                assert_eq!(
+                    $nonmax::new(19).unwrap().write_radix(2, &mut buf),
+                    Ok("10011")
+                );
+                assert_eq!($nonmax::new(35).unwrap().write_radix(36, &mut buf), Ok("z"));
+
+                for &radix in [2, 8, 10, 16, 36].iter() {
+                    let len = $nonmax::max_radix_len(radix);
+                    for &value in [$nonmax::MIN, $nonmax::MAX].iter() {
+                        assert!(value.write_radix(radix, &mut buf).unwrap().len() <= len);
+                        assert!(value.write_radix(radix, &mut buf[..len]).is_ok());
+                    }
+                }
+
+                let len = $nonmax::MAX.write_radix(10, &mut buf).unwrap().len();
+                assert_eq!(
+                    $nonmax::MAX.write_radix(10, &mut buf[..len - 1]),
+                    Err(BufferTooSmall(()))
+                );
+            }
+
+            #[test]
+            #[cfg(feature = "std")] // format!
+            fn write_radix_matches_fmt() {
+                let mut buf = [0; 130];
+                for value in [$nonmax::MIN, $nonmax::ZERO, $nonmax::MAX].iter() {
+                    let decimal = value.write_radix(10, &mut buf).unwrap();
+                    assert_eq!(decimal, format!("{}", value));
+                }
+            }
+
+            #[test]
+            #[cfg(feature = "std")] // DefaultHasher
+            fn hash_matches_primitive() {
+                use std::collections::hash_map::DefaultHasher;
+                use std::hash::{Hash, Hasher};
+
+                fn hash_of<T: Hash>(value: T) -> u64 {
+                    let mut hasher = DefaultHasher::new();
+                    value.hash(&mut hasher);
+                    hasher.finish()
+                }
+
+                for &value in [$nonmax::MIN, $nonmax::ZERO, $nonmax::ONE, $nonmax::MAX].iter() {
+                    assert_eq!(hash_of(value), hash_of(value.get()));
+                    assert_eq!(hash_of(value), hash_of($nonmax::new(value.get()).unwrap()));
+                }
+            }
+
+            #[test]
+            fn new_branchless() {
+                for &value in [$primitive::MIN, 0, 1, $primitive::MAX - 1, $primitive::MAX].iter() {
+                    assert_eq!($nonmax::new_branchless(value), $nonmax::new(value));
+                }
+            }
+
+            #[test]
+            fn parse_detailed() {
+                assert_eq!($nonmax::parse_detailed("12"), Ok($nonmax::new(12).unwrap()));
+                assert_eq!(
+                    $nonmax::parse_detailed("+12"),
+                    Ok($nonmax::new(12).unwrap())
+                );
+                assert_eq!($nonmax::parse_detailed(""), Err(ParseDetail::Empty));
+                assert_eq!($nonmax::parse_detailed("+"), Err(ParseDetail::InvalidDigit));
+                assert_eq!(
+                    $nonmax::parse_detailed("1x"),
+                    Err(ParseDetail::InvalidDigit)
+                );
+                assert_eq!(
+                    $nonmax::parse_detailed("999999999999999999999999999999999999999999"),
+                    Err(ParseDetail::Overflow)
+                );
+            }
+
+            #[test]
+            #[cfg(feature = "std")] // to_string
+            fn parse_detailed_max() {
+                assert_eq!(
+                    $nonmax::parse_detailed(&$primitive::MAX.to_string()),
+                    Err(ParseDetail::EqualsMax)
+                );
+                assert_eq!(
+                    $nonmax::parse_detailed(&($primitive::MAX - 1).to_string()),
+                    Ok($nonmax::MAX)
+                );
+                assert_eq!(
+                    $nonmax::parse_detailed(&$primitive::MIN.to_string()),
+                    Ok($nonmax::MIN)
+                );
+            }
+
+            #[test]
+            fn option_ext_sentinel() {
+                let some = Some($nonmax::ONE);
+                assert_eq!(some.to_sentinel(), 1);
+                assert_eq!(Option::<$nonmax>::from_sentinel(1), some);
+
+                let none: Option<$nonmax> = None;
+                assert_eq!(none.to_sentinel(), $primitive::MAX);
+                assert_eq!(Option::<$nonmax>::from_sentinel($primitive::MAX), none);
+
+                assert_eq!(Some($nonmax::MAX).to_sentinel(), $primitive::MAX - 1);
+            }
+
+            #[test]
+            fn checked_sum() {
+                let one = $nonmax::ONE;
+                assert_eq!($nonmax::checked_sum(&[]), Some($nonmax::ZERO));
+                assert_eq!($nonmax::checked_sum(&[one, one, one]), $nonmax::new(3));
+                assert_eq!($nonmax::checked_sum(&[$nonmax::MAX, one]), None);
+                assert_eq!(
+                    $nonmax::checked_sum(&[$nonmax::MAX, $nonmax::ZERO]),
+                    Some($nonmax::MAX)
+                );
+            }
+
+            #[test]
+            #[cfg(feature = "std")] // format!
+            fn display_matches_primitive() {
+                for value in [$nonmax::MIN, $nonmax::ZERO, $nonmax::ONE, $nonmax::MAX].iter() {
+                    let primitive = value.get();
+                    assert_eq!(value.to_string(), primitive.to_string());
+                    assert_eq!(format!("{:>50}", value), format!("{:>50}", primitive));
+                    assert_eq!(format!("{:<50}", value), format!("{:<50}", primitive));
+                    assert_eq!(format!("{:+}", value), format!("{:+}", primitive));
+                    assert_eq!(format!("{:050}", value), format!("{:050}", primitive));
+                    assert_eq!(format!("{:*^+50}", value), format!("{:*^+50}", primitive));
+                }
+            }
+
+            #[test]
+            fn try_from_ne_bytes() {
+                use core::convert::TryFrom;
+
+                let bytes = $nonmax::MAX.get().to_ne_bytes();
+                assert_eq!($nonmax::try_from(bytes), Ok($nonmax::MAX));
+                let bytes = $primitive::MAX.to_ne_bytes();
+                assert_eq!($nonmax::try_from(bytes), Err(TryFromIntError(())));
+            }
         }
     };
 
     ( signed, $nonmax: ident, $non_zero: ident, $primitive: ident ) => {
         nonmax!(common, $nonmax, $non_zero, $primitive);
-        // Nothing unique to signed versions (yet)
+
+        impl $nonmax {
+            /// Checked addition of a signed delta. Computes `self + delta`,
+            /// returning `None` if overflow occurred or the result is the
+            /// maximum value.
+            ///
+            /// This is the same as [`checked_add`](Self::checked_add), named
+            /// for moving a signed cursor by a signed offset.
+            #[inline]
+            pub const fn checked_add_delta(self, delta: Self) -> Option<Self> {
+                self.checked_add(delta)
+            }
+
+            /// Computes the absolute value, saturating at [`MAX`](Self::MAX).
+            ///
+            /// The absolute values of the primitive's `MIN` and `MIN + 1` are
+            /// too large to be non-max, so both saturate to `MAX`.
+            #[inline]
+            pub const fn saturating_abs(self) -> Self {
+                let value = self.get();
+                if value >= 0 {
+                    self
+                } else if value <= -$primitive::MAX {
+                    Self::MAX
+                } else {
+                    // Safety: `value` is greater than `-MAX`, so its absolute
+                    // value is less than `MAX`
+                    unsafe { Self::new_unchecked(-value) }
+                }
+            }
+
+            /// Returns the largest number of bytes that
+            /// [`write_radix`](Self::write_radix) can write for a value of this
+            /// type in the given radix, including the sign.
+            ///
+            /// This can be used to size buffers in constant expressions.
+            #[inline]
+            pub const fn max_radix_len(radix: u32) -> usize {
+                1 + radix_len(($primitive::MIN as i128).wrapping_abs() as u128, radix)
+            }
+
+            /// Writes the value in the given radix to the start of `buf` using
+            /// lowercase digits, with a leading `-` for negative values.
+            /// Returns the written part of the buffer as a string.
+            ///
+            /// Buffers of [`max_radix_len`](Self::max_radix_len) bytes are
+            /// always large enough.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not in the range from 2 to 36.
+            pub fn write_radix(self, radix: u32, buf: &mut [u8]) -> Result<&str, BufferTooSmall> {
+                let value = self.get();
+                // `wrapping_abs` of the minimum value is itself, which is
+                // still the correct magnitude once cast to `u128`.
+                write_radix(
+                    value < 0,
+                    (value as i128).wrapping_abs() as u128,
+                    radix,
+                    buf,
+                )
+            }
+        }
     };
 
     ( unsigned, $nonmax: ident, $non_zero: ident, $primitive: ident ) => {
         nonmax!(common, $nonmax, $non_zero, $primitive);
 
+        impl $nonmax {
+            /// Converts a zero-based non-max value into a one-based non-zero
+            /// value by adding one.
+            ///
+            /// This conversion can't fail: the largest non-max value is one
+            /// less than the primitive's maximum, so adding one never
+            /// overflows and never produces zero.
+            #[inline]
+            pub const fn to_one_based_nonzero(self) -> core::num::$non_zero {
+                // Safety: `self` is at most `MAX - 1`, so the sum is in the
+                // range `1..=MAX`
+                unsafe { core::num::$non_zero::new_unchecked(self.get() + 1) }
+            }
+
+            /// Converts a one-based non-zero value into a zero-based non-max
+            /// value by subtracting one. This is the inverse of
+            /// [`to_one_based_nonzero`](Self::to_one_based_nonzero).
+            ///
+            /// Like its inverse, this conversion can't fail.
+            #[inline]
+            pub const fn from_one_based_nonzero(value: core::num::$non_zero) -> Self {
+                // Safety: `value` is in the range `1..=MAX`, so the difference
+                // is in the range `0..=MAX - 1`
+                unsafe { Self::new_unchecked(value.get() - 1) }
+            }
+
+            /// Converts an `i64` into a non-max, clamping negative values to
+            /// [`ZERO`](Self::ZERO) and values that are too large to
+            /// [`MAX`](Self::MAX).
+            #[inline]
+            pub const fn saturating_from_i64(value: i64) -> Self {
+                if value < 0 {
+                    Self::ZERO
+                } else if value as u128 >= $primitive::MAX as u128 {
+                    Self::MAX
+                } else {
+                    // Safety: `value` is non-negative and less than the
+                    // primitive's maximum, so it fits and is not max
+                    unsafe { Self::new_unchecked(value as $primitive) }
+                }
+            }
+
+            /// Rounds the value down to the nearest multiple of `rhs`, or
+            /// returns `None` if `rhs` is zero.
+            #[inline]
+            pub const fn prev_multiple_of(self, rhs: Self) -> Option<Self> {
+                let value = self.get();
+                let rhs = rhs.get();
+                if rhs == 0 {
+                    None
+                } else {
+                    // Safety: the result is at most `self`, which is non-max
+                    Some(unsafe { Self::new_unchecked(value - value % rhs) })
+                }
+            }
+
+            /// Returns an iterator over the positions of the set bits in the
+            /// value, from least to most significant.
+            pub fn iter_set_bits(self) -> impl Iterator<Item = u32> {
+                let mut bits = self.get();
+                core::iter::from_fn(move || {
+                    if bits == 0 {
+                        None
+                    } else {
+                        let index = bits.trailing_zeros();
+                        // Clear the lowest set bit
+                        bits &= bits - 1;
+                        Some(index)
+                    }
+                })
+            }
+
+            /// Returns the largest number of bytes that
+            /// [`write_radix`](Self::write_radix) can write for a value of this
+            /// type in the given radix.
+            ///
+            /// This can be used to size buffers in constant expressions.
+            #[inline]
+            pub const fn max_radix_len(radix: u32) -> usize {
+                radix_len(($primitive::MAX - 1) as u128, radix)
+            }
+
+            /// Writes the value in the given radix to the start of `buf` using
+            /// lowercase digits. Returns the written part of the buffer as a
+            /// string.
+            ///
+            /// Buffers of [`max_radix_len`](Self::max_radix_len) bytes are
+            /// always large enough.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not in the range from 2 to 36.
+            pub fn write_radix(self, radix: u32, buf: &mut [u8]) -> Result<&str, BufferTooSmall> {
+                write_radix(false, self.get() as u128, radix, buf)
+            }
+
+            /// Returns how many times the value can be incremented before it
+            /// reaches [`MAX`](Self::MAX).
+            #[inline]
+            pub const fn remaining(self) -> $primitive {
+                ($primitive::MAX - 1) - self.get()
+            }
+
+            /// Maps `t` from the unit interval `0.0..=1.0` onto `0..=max`,
+            /// rounding to the nearest value.
+            ///
+            /// Values of `t` below zero are clamped to zero and values above
+            /// one are clamped to `max`. NaN maps to zero.
+            pub fn from_unit_interval(t: f64, max: Self) -> Self {
+                if t.is_nan() || t <= 0.0 {
+                    return Self::ZERO;
+                }
+                if t >= 1.0 {
+                    return max;
+                }
+
+                // `f64::round` isn't available without std, so round half up
+                // by hand. Precision loss for wide types can push the result
+                // past `max`, so it needs to be clamped.
+                let value = (t * max.get() as f64 + 0.5) as $primitive;
+                if value >= max.get() {
+                    max
+                } else {
+                    // Safety: `value` is less than `max`, which is non-max
+                    unsafe { Self::new_unchecked(value) }
+                }
+            }
+
+            /// Bitwise-and with a primitive value. This is the same as the `&`
+            /// operator, but can be used in constant expressions.
+            #[inline]
+            pub const fn const_bitand_primitive(self, rhs: $primitive) -> Self {
+                // Safety: since `self` is non-max, the result of the
+                // bitwise-and will be non-max regardless of the value of `rhs`
+                unsafe { Self::new_unchecked(self.get() & rhs) }
+            }
+
+            /// Splits the value into 4-bit nibbles, most significant first.
+            pub const fn to_nibbles(self) -> [u8; core::mem::size_of::<$primitive>() * 2] {
+                let mut nibbles = [0; core::mem::size_of::<$primitive>() * 2];
+                let mut value = self.get();
+                let mut i = nibbles.len();
+                while i > 0 {
+                    i -= 1;
+                    nibbles[i] = (value & 0xf) as u8;
+                    value >>= 4;
+                }
+                nibbles
+            }
+
+            /// Combines 4-bit nibbles, most significant first, into a non-max
+            /// value. Returns `None` if any nibble is larger than `0xf` or the
+            /// combined value is the maximum value.
+            pub const fn from_nibbles(
+                nibbles: [u8; core::mem::size_of::<$primitive>() * 2],
+            ) -> Option<Self> {
+                let mut value: $primitive = 0;
+                let mut i = 0;
+                while i < nibbles.len() {
+                    if nibbles[i] > 0xf {
+                        return None;
+                    }
+                    value = (value << 4) | nibbles[i] as $primitive;
+                    i += 1;
+                }
+                Self::new(value)
+            }
+
+            /// Wrapping multiplication within the non-max domain. Computes
+            /// `(self * rhs) % MAX`, where `MAX` is the primitive's maximum
+            /// value, so the result is always a valid non-max value.
+            ///
+            /// Note that this wraps at the primitive's maximum rather than one
+            /// past it like the primitive's `wrapping_mul`.
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                // Since `2^BITS` is congruent to 1 modulo `MAX`, doubling is a
+                // rotation and addition carries around from the top bit, which
+                // lets us multiply without a wider type.
+                let mut result: $primitive = 0;
+                let mut addend = self.get();
+                let mut rhs = rhs.get();
+
+                while rhs != 0 {
+                    if rhs & 1 == 1 {
+                        let (sum, carry) = result.overflowing_add(addend);
+                        result = sum + carry as $primitive;
+                        if result == $primitive::MAX {
+                            result = 0;
+                        }
+                    }
+
+                    addend = addend.rotate_left(1);
+                    rhs >>= 1;
+                }
+
+                // Safety: `result` is reduced below the maximum after every
+                // addition
+                unsafe { Self::new_unchecked(result) }
+            }
+        }
+
         impl core::ops::BitAnd<$nonmax> for $primitive {
             type Output = $nonmax;
             fn bitand(self, rhs: $nonmax) -> Self::Output {
@@ -424,6 +1824,17 @@ impl_nonmax_from!(NonMaxU32, NonMaxU64);
 impl_nonmax_from!(NonMaxU32, NonMaxU128);
 impl_nonmax_from!(NonMaxU64, NonMaxU128);
 
+// std doesn't implement `From<u32> for usize`, even on targets where it would
+// be lossless, so this conversion can't go through `impl_nonmax_from`.
+#[cfg(target_pointer_width = "64")]
+impl From<NonMaxU32> for NonMaxUsize {
+    #[inline]
+    fn from(small: NonMaxU32) -> Self {
+        // SAFETY: usize is 64 bits wide, so the value fits and is non-max
+        unsafe { Self::new_unchecked(small.get() as usize) }
+    }
+}
+
 // Non-max Signed -> Non-max Signed
 impl_nonmax_from!(NonMaxI8, NonMaxI16);
 impl_nonmax_from!(NonMaxI8, NonMaxI32);
@@ -451,6 +1862,25 @@ impl_nonmax_from!(NonMaxU32, NonMaxI64);
 impl_nonmax_from!(NonMaxU32, NonMaxI128);
 impl_nonmax_from!(NonMaxU64, NonMaxI128);
 
+macro_rules! impl_nonmax_try_from {
+    ( $large: ty, $small: ty ) => {
+        impl core::convert::TryFrom<$large> for $small {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(large: $large) -> Result<Self, Self::Error> {
+                let value = core::convert::TryFrom::try_from(large.get())?;
+                Self::new(value).ok_or(TryFromIntError(()))
+            }
+        }
+    };
+}
+
+// Non-max usize -> Non-max Unsigned
+impl_nonmax_try_from!(NonMaxUsize, NonMaxU8);
+impl_nonmax_try_from!(NonMaxUsize, NonMaxU16);
+impl_nonmax_try_from!(NonMaxUsize, NonMaxU32);
+
 // https://doc.rust-lang.org/1.47.0/src/core/convert/num.rs.html#383-407
 macro_rules! impl_smaller_from {
     ( $small: ty, $large: ty ) => {
@@ -505,7 +1935,568 @@ impl_smaller_from!(u32, NonMaxI64);
 impl_smaller_from!(u32, NonMaxI128);
 impl_smaller_from!(u64, NonMaxI128);
 
-#[cfg(test)]
+// u128 is left out, since its large values don't fit in an i128
+macro_rules! impl_as_i128 {
+    ( $( $nonmax: ty ),* ) => {
+        $(
+            impl $nonmax {
+                /// Returns the value as an `i128`, which can hold every value
+                /// of this type without loss.
+                #[inline]
+                pub const fn as_i128(self) -> i128 {
+                    self.get() as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_as_i128!(
+    NonMaxI8,
+    NonMaxI16,
+    NonMaxI32,
+    NonMaxI64,
+    NonMaxI128,
+    NonMaxIsize,
+    NonMaxU8,
+    NonMaxU16,
+    NonMaxU32,
+    NonMaxU64,
+    NonMaxUsize
+);
+
+macro_rules! impl_cast_sign {
+    ( $unsigned: ident, $unsigned_primitive: ident, $signed: ident, $signed_primitive: ident ) => {
+        impl $unsigned {
+            /// Reinterprets the bits of the value as the signed type of the
+            /// same width, like an `as` cast. Returns `None` if the result is
+            /// the signed type's maximum value.
+            ///
+            /// Unlike `TryFrom`, which keeps the numeric value, this keeps the
+            /// bit pattern, so large values become negative.
+            #[inline]
+            pub const fn cast_signed(self) -> Option<$signed> {
+                $signed::new(self.get() as $signed_primitive)
+            }
+        }
+
+        impl $signed {
+            /// Reinterprets the bits of the value as the unsigned type of the
+            /// same width, like an `as` cast. Returns `None` if the result is
+            /// the unsigned type's maximum value, which happens for `-1`.
+            ///
+            /// Unlike `TryFrom`, which keeps the numeric value, this keeps the
+            /// bit pattern, so negative values become large.
+            #[inline]
+            pub const fn cast_unsigned(self) -> Option<$unsigned> {
+                $unsigned::new(self.get() as $unsigned_primitive)
+            }
+        }
+    };
+}
+
+impl_cast_sign!(NonMaxU8, u8, NonMaxI8, i8);
+impl_cast_sign!(NonMaxU16, u16, NonMaxI16, i16);
+impl_cast_sign!(NonMaxU32, u32, NonMaxI32, i32);
+impl_cast_sign!(NonMaxU64, u64, NonMaxI64, i64);
+impl_cast_sign!(NonMaxU128, u128, NonMaxI128, i128);
+impl_cast_sign!(NonMaxUsize, usize, NonMaxIsize, isize);
+
+macro_rules! impl_saturating_as {
+    ( $large: ident, $large_primitive: ident => $( $method: ident: $small: ident, $small_primitive: ident );* ) => {
+        impl $large {
+            $(
+                /// Converts the value to a narrower non-max type, saturating at
+                /// the narrower type's maximum value if it's out of range.
+                #[inline]
+                pub const fn $method(self) -> $small {
+                    if self.get() >= $small::MAX.get() as $large_primitive {
+                        $small::MAX
+                    } else {
+                        // Safety: the value is less than the narrower type's
+                        // maximum value, so it fits and is non-max
+                        unsafe { $small::new_unchecked(self.get() as $small_primitive) }
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_saturating_as!(NonMaxU16, u16 =>
+    saturating_as_u8: NonMaxU8, u8
+);
+impl_saturating_as!(NonMaxU32, u32 =>
+    saturating_as_u8: NonMaxU8, u8;
+    saturating_as_u16: NonMaxU16, u16
+);
+impl_saturating_as!(NonMaxU64, u64 =>
+    saturating_as_u8: NonMaxU8, u8;
+    saturating_as_u16: NonMaxU16, u16;
+    saturating_as_u32: NonMaxU32, u32
+);
+impl_saturating_as!(NonMaxU128, u128 =>
+    saturating_as_u8: NonMaxU8, u8;
+    saturating_as_u16: NonMaxU16, u16;
+    saturating_as_u32: NonMaxU32, u32;
+    saturating_as_u64: NonMaxU64, u64
+);
+impl_saturating_as!(NonMaxUsize, usize =>
+    saturating_as_u8: NonMaxU8, u8;
+    saturating_as_u16: NonMaxU16, u16
+);
+#[cfg(not(target_pointer_width = "16"))]
+impl_saturating_as!(NonMaxUsize, usize =>
+    saturating_as_u32: NonMaxU32, u32
+);
+
+macro_rules! impl_try_into_primitive {
+    ( $large: ident, $large_primitive: ident => $( $method: ident: $small_primitive: ident );* ) => {
+        impl $large {
+            $(
+                /// Converts the value to a narrower primitive type, failing if
+                /// it's out of range. The primitive's maximum value is allowed.
+                #[inline]
+                pub const fn $method(self) -> Result<$small_primitive, TryFromIntError> {
+                    if self.get() > $small_primitive::MAX as $large_primitive {
+                        Err(TryFromIntError(()))
+                    } else {
+                        Ok(self.get() as $small_primitive)
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_try_into_primitive!(NonMaxU16, u16 =>
+    try_into_u8: u8
+);
+impl_try_into_primitive!(NonMaxU32, u32 =>
+    try_into_u8: u8;
+    try_into_u16: u16
+);
+impl_try_into_primitive!(NonMaxU64, u64 =>
+    try_into_u8: u8;
+    try_into_u16: u16;
+    try_into_u32: u32
+);
+impl_try_into_primitive!(NonMaxU128, u128 =>
+    try_into_u8: u8;
+    try_into_u16: u16;
+    try_into_u32: u32;
+    try_into_u64: u64
+);
+impl_try_into_primitive!(NonMaxUsize, usize =>
+    try_into_u8: u8;
+    try_into_u16: u16
+);
+#[cfg(not(target_pointer_width = "16"))]
+impl_try_into_primitive!(NonMaxUsize, usize =>
+    try_into_u32: u32
+);
+
+macro_rules! impl_checked_from {
+    ( $target: ident from primitives [ $( $source: ty ),* ] ) => {
+        $(
+            impl CheckedFrom<$source> for $target {
+                #[inline]
+                fn checked_from(value: $source) -> Option<Self> {
+                    core::convert::TryFrom::try_from(value)
+                        .ok()
+                        .and_then(Self::new)
+                }
+            }
+        )*
+    };
+    ( $target: ident from nonmax [ $( $source: ty ),* ] ) => {
+        $(
+            impl CheckedFrom<$source> for $target {
+                #[inline]
+                fn checked_from(value: $source) -> Option<Self> {
+                    Self::checked_from(value.get())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_checked_from_all {
+    ( $( $target: ident ),* ) => {
+        $(
+            impl_checked_from!($target from primitives [
+                i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+            ]);
+            impl_checked_from!($target from nonmax [
+                NonMaxI8, NonMaxI16, NonMaxI32, NonMaxI64, NonMaxI128, NonMaxIsize,
+                NonMaxU8, NonMaxU16, NonMaxU32, NonMaxU64, NonMaxU128, NonMaxUsize
+            ]);
+        )*
+    };
+}
+
+impl_checked_from_all!(
+    NonMaxI8,
+    NonMaxI16,
+    NonMaxI32,
+    NonMaxI64,
+    NonMaxI128,
+    NonMaxIsize,
+    NonMaxU8,
+    NonMaxU16,
+    NonMaxU32,
+    NonMaxU64,
+    NonMaxU128,
+    NonMaxUsize
+);
+
+impl NonMaxUsize {
+    /// Returns a reference to the element of `slice` at this index, or `None`
+    /// if the index is out of bounds.
+    #[inline]
+    pub fn checked_index<T>(self, slice: &[T]) -> Option<&T> {
+        slice.get(self.get())
+    }
+
+    /// Returns a mutable reference to the element of `slice` at this index, or
+    /// `None` if the index is out of bounds.
+    #[inline]
+    pub fn checked_index_mut<T>(self, slice: &mut [T]) -> Option<&mut T> {
+        slice.get_mut(self.get())
+    }
+}
+
+impl NonMaxU8 {
+    /// Converts an ASCII digit byte (`b'0'` to `b'9'`) into its value, or
+    /// returns `None` if the byte isn't an ASCII digit.
+    #[inline]
+    pub const fn from_ascii_digit(c: u8) -> Option<Self> {
+        match c {
+            // Safety: the digit's value is at most 9
+            b'0'..=b'9' => Some(unsafe { Self::new_unchecked(c - b'0') }),
+            _ => None,
+        }
+    }
+
+    /// Returns `done` as a percentage of `total`, rounded down and clamped
+    /// to 100. Returns zero if `total` is zero.
+    #[inline]
+    pub const fn percent_of(done: u64, total: u64) -> Self {
+        if total == 0 {
+            return Self::ZERO;
+        }
+
+        // Widen so that multiplying by 100 can't overflow
+        let percent = done as u128 * 100 / total as u128;
+        let percent = if percent > 100 { 100 } else { percent as u8 };
+
+        // Safety: the percentage is at most 100
+        unsafe { Self::new_unchecked(percent) }
+    }
+}
+
+impl NonMaxU32 {
+    /// Splits the value into its high and low 16-bit halves.
+    #[inline]
+    pub const fn split_u16(self) -> (u16, u16) {
+        let value = self.get();
+        ((value >> 16) as u16, value as u16)
+    }
+
+    /// Combines high and low 16-bit halves into a value, returning `None` if
+    /// the result is the maximum value. This is the inverse of
+    /// [`split_u16`](Self::split_u16).
+    #[inline]
+    pub const fn from_u16_parts(high: u16, low: u16) -> Option<Self> {
+        Self::new((high as u32) << 16 | low as u32)
+    }
+}
+
+/// Masks a hash to get a slot index, for hash tables whose capacity is a power
+/// of two. The mask is usually the capacity minus one.
+///
+/// On targets where `usize` is smaller than 64 bits, the masked value is
+/// truncated, so masks should fit in a `usize`.
+#[inline]
+pub const fn mask_index(hash: u64, mask: NonMaxU64) -> usize {
+    (hash & mask.get()) as usize
+}
+
+/// Generates unique [`NonMaxU32`] ids in increasing order, starting from zero.
+///
+/// Ids are handed out by iterating. Once every id up to and including
+/// [`NonMaxU32::MAX`] has been handed out, the generator is exhausted and
+/// only returns `None`.
+#[derive(Clone, Debug)]
+pub struct IdGen {
+    next: Option<NonMaxU32>,
+}
+
+impl IdGen {
+    /// Creates a generator whose first id is zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(NonMaxU32::ZERO)
+    }
+
+    /// Creates a generator whose first id is `first`, like when resuming from
+    /// a previously saved id.
+    #[inline]
+    pub const fn starting_at(first: NonMaxU32) -> Self {
+        Self { next: Some(first) }
+    }
+}
+
+impl Default for IdGen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for IdGen {
+    type Item = NonMaxU32;
+
+    fn next(&mut self) -> Option<NonMaxU32> {
+        let id = self.next?;
+        self.next = id.checked_add(NonMaxU32::ONE);
+        Some(id)
+    }
+}
+
+impl core::iter::FusedIterator for IdGen {}
+
+/// Three [`NonMaxU8`] values packed into a `u32`, like the channels of a
+/// palette-indexed color.
+///
+/// The values are stored in the lower three bytes, leaving the top byte
+/// unused. Since the packed value can't be `u32::MAX`,
+/// `Option<NonMaxU8Triple>` is the same size as `NonMaxU8Triple`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct NonMaxU8Triple(NonMaxU32);
+
+impl NonMaxU8Triple {
+    /// Packs three values into a triple.
+    #[inline]
+    pub const fn new(r: NonMaxU8, g: NonMaxU8, b: NonMaxU8) -> Self {
+        let packed = (r.get() as u32) << 16 | (g.get() as u32) << 8 | b.get() as u32;
+
+        // Safety: the top byte is zero, so the value can't be the maximum
+        Self(unsafe { NonMaxU32::new_unchecked(packed) })
+    }
+
+    /// Returns the first value of the triple.
+    #[inline]
+    pub const fn r(self) -> NonMaxU8 {
+        // Safety: each byte was packed from a non-max value
+        unsafe { NonMaxU8::new_unchecked((self.0.get() >> 16) as u8) }
+    }
+
+    /// Returns the second value of the triple.
+    #[inline]
+    pub const fn g(self) -> NonMaxU8 {
+        // Safety: each byte was packed from a non-max value
+        unsafe { NonMaxU8::new_unchecked((self.0.get() >> 8) as u8) }
+    }
+
+    /// Returns the third value of the triple.
+    #[inline]
+    pub const fn b(self) -> NonMaxU8 {
+        // Safety: each byte was packed from a non-max value
+        unsafe { NonMaxU8::new_unchecked(self.0.get() as u8) }
+    }
+}
+
+impl core::fmt::Debug for NonMaxU8Triple {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NonMaxU8Triple")
+            .field("r", &self.r())
+            .field("g", &self.g())
+            .field("b", &self.b())
+            .finish()
+    }
+}
+
+/// A generational index, like the handles used by entity-component systems,
+/// made of a [`NonMaxU32`] index and a `u32` generation.
+///
+/// Since the index is non-max, `Option<GenIndex>` is the same size as
+/// `GenIndex`, and the index bits of a packed handle are never all ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GenIndex {
+    index: NonMaxU32,
+    generation: u32,
+}
+
+impl GenIndex {
+    /// Creates a generational index from its parts.
+    #[inline]
+    pub const fn new(index: NonMaxU32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the index part.
+    #[inline]
+    pub const fn index(self) -> NonMaxU32 {
+        self.index
+    }
+
+    /// Returns the generation part.
+    #[inline]
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+
+    /// Packs the index into a `u64` handle, with the generation in the upper
+    /// 32 bits and the index in the lower 32 bits.
+    #[inline]
+    pub const fn to_u64(self) -> u64 {
+        (self.generation as u64) << 32 | self.index.get() as u64
+    }
+
+    /// Unpacks a handle created by [`to_u64`](Self::to_u64), returning `None`
+    /// if the index bits are all ones.
+    #[inline]
+    pub const fn from_u64(handle: u64) -> Option<Self> {
+        match NonMaxU32::new(handle as u32) {
+            Some(index) => Some(Self::new(index, (handle >> 32) as u32)),
+            None => None,
+        }
+    }
+}
+
+macro_rules! nonmax_float {
+    ( $nonmax: ident, $non_zero: ident, $float: ident, $bits: ident, $signed_bits: ident ) => {
+        /// A floating point number that is known not to equal its largest
+        /// finite value.
+        ///
+        /// Floats have no niche of their own, so this stores the bits of the
+        /// value XORed with the bits of the forbidden value. This makes an
+        /// `Option` of this type the same size as the float.
+        ///
+        /// Values are compared with a total order over their bits, like
+        /// `total_cmp`: negative NaNs are less than every other value, `-0.0`
+        /// is less than `0.0`, and positive NaNs are greater than every other
+        /// value.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct $nonmax(core::num::$non_zero);
+
+        impl $nonmax {
+            /// Creates a new non-max float if the given value is not the
+            /// largest finite value.
+            #[inline]
+            pub fn new(value: $float) -> Option<Self> {
+                core::num::$non_zero::new(value.to_bits() ^ $float::MAX.to_bits()).map(Self)
+            }
+
+            /// Creates a new non-max float without checking the value.
+            ///
+            /// # Safety
+            ///
+            /// The value must not equal the largest finite value of the float
+            /// type.
+            #[inline]
+            pub unsafe fn new_unchecked(value: $float) -> Self {
+                Self(core::num::$non_zero::new_unchecked(
+                    value.to_bits() ^ $float::MAX.to_bits(),
+                ))
+            }
+
+            /// Returns the value as a primitive type.
+            #[inline]
+            pub fn get(self) -> $float {
+                $float::from_bits(self.0.get() ^ $float::MAX.to_bits())
+            }
+
+            /// Returns the bits of the value, flipped so that comparing them
+            /// as signed integers gives the total order.
+            fn total_order_key(self) -> $signed_bits {
+                let bits = self.get().to_bits() as $signed_bits;
+                // Flip every bit but the sign for negative values, which
+                // reverses their order
+                let shift = core::mem::size_of::<$bits>() as u32 * 8 - 1;
+                bits ^ (((bits >> shift) as $bits) >> 1) as $signed_bits
+            }
+        }
+
+        impl core::cmp::Ord for $nonmax {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.total_order_key().cmp(&other.total_order_key())
+            }
+        }
+
+        impl core::cmp::PartialOrd for $nonmax {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl From<$nonmax> for $float {
+            fn from(value: $nonmax) -> Self {
+                value.get()
+            }
+        }
+
+        impl core::convert::TryFrom<$float> for $nonmax {
+            type Error = TryFromFloatError;
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(TryFromFloatError(()))
+            }
+        }
+
+        impl core::fmt::Debug for $nonmax {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl core::fmt::Display for $nonmax {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+nonmax_float!(NonMaxF32, NonZeroU32, f32, u32, i32);
+nonmax_float!(NonMaxF64, NonZeroU64, f64, u64, i64);
+
+/// Builds an array of non-max values from constant expressions, failing to
+/// compile if any of them is the maximum value.
+///
+/// ```
+/// use nonmax::NonMaxU16;
+///
+/// const TABLE: [NonMaxU16; 3] = nonmax::table![NonMaxU16; 1, 2, 3];
+/// assert_eq!(TABLE[2].get(), 3);
+/// ```
+///
+/// The maximum value is rejected at compile time:
+///
+/// ```compile_fail
+/// const TABLE: [nonmax::NonMaxU8; 2] = nonmax::table![NonMaxU8; 1, 255];
+/// ```
+#[macro_export]
+macro_rules! table {
+    ( $nonmax: ident; $( $value: expr ),* $(,)? ) => {
+        [$({
+            #[allow(clippy::out_of_bounds_indexing)]
+            const VALUE: $crate::$nonmax = match $crate::$nonmax::new($value) {
+                Some(value) => value,
+                None => {
+                    // Panicking in constants isn't available on our MSRV, but
+                    // indexing out of bounds still fails to compile.
+                    let max_value_in_table: [$crate::$nonmax; 0] = [];
+                    max_value_in_table[0]
+                }
+            };
+            VALUE
+        }),*]
+    };
+}
+
+#[cfg(test)]
 mod ops {
     use super::*;
 
@@ -543,4 +2534,932 @@ mod ops {
             }
         }
     }
+
+    #[test]
+    fn shift_unsigned() {
+        for value in 0..u8::MAX {
+            let nonmax = NonMaxU8::new(value).unwrap();
+            for rhs in 0..8 {
+                assert_eq!(value << rhs, (nonmax << rhs).get());
+                assert_eq!(value >> rhs, (nonmax >> rhs).get());
+            }
+            assert_eq!(nonmax.checked_shl(8), None);
+            assert_eq!(nonmax.checked_shr(8), None);
+        }
+    }
+
+    #[test]
+    fn shift_signed() {
+        for value in i8::MIN..i8::MAX {
+            let nonmax = NonMaxI8::new(value).unwrap();
+            for rhs in 0..8 {
+                assert_eq!(value << rhs, (nonmax << rhs).get());
+                assert_eq!(value >> rhs, (nonmax >> rhs).get());
+            }
+            assert_eq!(nonmax.checked_shl(8), None);
+            assert_eq!(nonmax.checked_shr(8), None);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn shl_overflow() {
+        let _ = NonMaxU32::ONE << 32;
+    }
+
+    #[test]
+    fn wrapping_mul_unsigned() {
+        for left in 0..u8::MAX {
+            let nmleft = NonMaxU8::new(left).unwrap();
+            for right in 0..u8::MAX {
+                let nmright = NonMaxU8::new(right).unwrap();
+                let expected = (left as u32 * right as u32 % u8::MAX as u32) as u8;
+                assert_eq!(expected, nmleft.wrapping_mul(nmright).get());
+            }
+        }
+
+        let large = NonMaxU128::MAX;
+        assert_eq!(large.wrapping_mul(large).get(), 1);
+        assert_eq!(large.wrapping_mul(NonMaxU128::ONE), large);
+        assert_eq!(large.wrapping_mul(NonMaxU128::ZERO), NonMaxU128::ZERO);
+    }
+
+    #[test]
+    fn const_bitand_primitive() {
+        const HANDLE: NonMaxU16 = NonMaxU16::MAX.const_bitand_primitive(0x0fff);
+        assert_eq!(HANDLE.get(), 0x0ffe);
+
+        for left in 0..u8::MAX {
+            let nmleft = NonMaxU8::new(left).unwrap();
+            for right in 0..=u8::MAX {
+                assert_eq!(nmleft & right, nmleft.const_bitand_primitive(right));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add_unsigned() {
+        for left in 0..u8::MAX {
+            let nmleft = NonMaxU8::new(left).unwrap();
+            for right in 0..u8::MAX {
+                let nmright = NonMaxU8::new(right).unwrap();
+                let vanilla = left.checked_add(right).and_then(NonMaxU8::new);
+                let checked = nmleft.checked_add(nmright);
+                assert_eq!(vanilla, checked);
+                assert_eq!(checked.is_none(), nmleft.would_overflow_add(nmright));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add_signed() {
+        for left in i8::MIN..i8::MAX {
+            let nmleft = NonMaxI8::new(left).unwrap();
+            for right in i8::MIN..i8::MAX {
+                let nmright = NonMaxI8::new(right).unwrap();
+                let vanilla = left.checked_add(right).and_then(NonMaxI8::new);
+                let checked = nmleft.checked_add(nmright);
+                assert_eq!(vanilla, checked);
+                assert_eq!(checked.is_none(), nmleft.would_overflow_add(nmright));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add_delta() {
+        for cursor in i8::MIN..i8::MAX {
+            let nmcursor = NonMaxI8::new(cursor).unwrap();
+            for delta in i8::MIN..i8::MAX {
+                let nmdelta = NonMaxI8::new(delta).unwrap();
+                let vanilla = cursor.checked_add(delta).and_then(NonMaxI8::new);
+                assert_eq!(vanilla, nmcursor.checked_add_delta(nmdelta));
+            }
+        }
+    }
+
+    #[test]
+    fn shift_assign() {
+        let mut value = NonMaxU32::new(3).unwrap();
+        value <<= 4;
+        assert_eq!(value.get(), 48);
+        value >>= 3;
+        assert_eq!(value.get(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shl_assign_overflow() {
+        let mut value = NonMaxU32::ONE;
+        value <<= 32;
+    }
+}
+
+#[cfg(test)]
+mod methods {
+    use super::*;
+
+    #[test]
+    fn cardinality() {
+        assert_eq!(NonMaxU8::cardinality(), 255);
+        assert_eq!(NonMaxI8::cardinality(), 255);
+        assert_eq!(NonMaxU128::cardinality(), u128::MAX);
+    }
+
+    #[test]
+    fn get_ref() {
+        const VALUES: [NonMaxU8; 3] = [NonMaxU8::ZERO, NonMaxU8::ONE, NonMaxU8::MAX];
+        const SECOND: u8 = NonMaxU8::get_ref(&VALUES[1]);
+        assert_eq!(SECOND, 1);
+        assert_eq!(NonMaxU8::get_ref(&VALUES[2]), 254);
+    }
+
+    #[test]
+    fn saturating_add_primitive() {
+        let count = NonMaxU32::new(10).unwrap();
+        assert_eq!(count.saturating_add_primitive(5).get(), 15);
+        assert_eq!(count.saturating_add_primitive(u32::MAX - 3), NonMaxU32::MAX);
+
+        let signed = NonMaxI8::new(-100).unwrap();
+        assert_eq!(signed.saturating_add_primitive(-100).get(), i8::MIN);
+        assert_eq!(
+            signed.saturating_add_primitive(i8::MAX),
+            NonMaxI8::new(27).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_one_based_nonzero() {
+        for value in 0..u8::MAX {
+            let nonmax = NonMaxU8::new(value).unwrap();
+            assert_eq!(nonmax.to_one_based_nonzero().get(), value + 1);
+        }
+
+        assert_eq!(NonMaxU32::ZERO.to_one_based_nonzero().get(), 1);
+        assert_eq!(NonMaxU32::MAX.to_one_based_nonzero().get(), u32::MAX);
+    }
+
+    #[test]
+    fn from_one_based_nonzero() {
+        for value in 1..=u8::MAX {
+            let nonzero = core::num::NonZeroU8::new(value).unwrap();
+            let nonmax = NonMaxU8::from_one_based_nonzero(nonzero);
+            assert_eq!(nonmax.get(), value - 1);
+            assert_eq!(nonmax.to_one_based_nonzero(), nonzero);
+        }
+
+        let one = core::num::NonZeroU32::new(1).unwrap();
+        let max = core::num::NonZeroU32::new(u32::MAX).unwrap();
+        assert_eq!(NonMaxU32::from_one_based_nonzero(one), NonMaxU32::ZERO);
+        assert_eq!(NonMaxU32::from_one_based_nonzero(max), NonMaxU32::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut seen = [false; 256];
+        for byte in 0..=u8::MAX {
+            let value = NonMaxU8::arbitrary(&mut Unstructured::new(&[byte])).unwrap();
+            seen[value.get() as usize] = true;
+        }
+        assert!(seen[0]);
+        assert!(seen[254]);
+        assert!(!seen[255]);
+
+        let mut seen = [false; 256];
+        for byte in 0..=u8::MAX {
+            let value = NonMaxI8::arbitrary(&mut Unstructured::new(&[byte])).unwrap();
+            seen[value.get() as u8 as usize] = true;
+        }
+        assert!(seen[i8::MIN as u8 as usize]);
+        assert!(seen[(i8::MAX - 1) as u8 as usize]);
+        assert!(!seen[i8::MAX as u8 as usize]);
+    }
+
+    #[test]
+    fn const_cmp_primitive() {
+        const LEN: usize = 16;
+        const INDEX: NonMaxUsize = NonMaxUsize::ONE;
+
+        // Fails to compile if the comparison doesn't hold.
+        const _: [(); 1] = [(); INDEX.const_lt_primitive(LEN) as usize];
+        const _: [(); 0] = [(); INDEX.const_gt_primitive(LEN) as usize];
+    }
+
+    #[test]
+    fn option_ffi() {
+        assert_eq!(NonMaxU32::option_into_ffi(None), 0xFFFF_FFFF);
+        assert_eq!(NonMaxU32::option_from_ffi(0xFFFF_FFFF), None);
+        assert_eq!(NonMaxU32::option_into_ffi(Some(NonMaxU32::ONE)), 1);
+        assert_eq!(NonMaxU32::option_from_ffi(1), Some(NonMaxU32::ONE));
+    }
+
+    #[test]
+    #[cfg(feature = "std")] // format!
+    fn bounds() {
+        fn describe<T: NonMaxBounds + core::fmt::Display>() -> String {
+            format!("{}..={}", T::MIN, T::MAX)
+        }
+
+        assert_eq!(describe::<NonMaxU8>(), "0..=254");
+        assert_eq!(describe::<NonMaxI8>(), "-128..=126");
+    }
+
+    #[test]
+    fn from_ascii_radix() {
+        assert_eq!(NonMaxU8::from_ascii_radix(b"1A", 16).unwrap().get(), 0x1a);
+        assert_eq!(NonMaxU8::from_ascii_radix(b"fe", 16).unwrap().get(), 0xfe);
+        assert_eq!(
+            NonMaxU32::from_ascii_radix(b"DEADBEEF", 16).unwrap().get(),
+            0xdeadbeef
+        );
+
+        // Overflow
+        NonMaxU8::from_ascii_radix(b"100", 16).unwrap_err();
+        // Maximum value
+        NonMaxU8::from_ascii_radix(b"FF", 16).unwrap_err();
+        NonMaxU32::from_ascii_radix(b"ffffffff", 16).unwrap_err();
+        // Not a hex digit, or not UTF-8 at all
+        NonMaxU8::from_ascii_radix(b"1G", 16).unwrap_err();
+        NonMaxU8::from_ascii_radix(&[0xff], 16).unwrap_err();
+        NonMaxU8::from_ascii_radix(b"", 16).unwrap_err();
+    }
+
+    #[test]
+    fn delta_decode() {
+        let n = |value| NonMaxU8::new(value).unwrap();
+
+        let deltas = [n(1), n(10), n(100), n(100), n(100)];
+        let mut decoded = NonMaxU8::delta_decode(deltas.iter().copied(), n(5));
+        assert_eq!(decoded.next(), Some(Some(n(6))));
+        assert_eq!(decoded.next(), Some(Some(n(16))));
+        assert_eq!(decoded.next(), Some(Some(n(116))));
+        assert_eq!(decoded.next(), Some(Some(n(216))));
+        assert_eq!(decoded.next(), Some(None));
+        assert_eq!(decoded.next(), None);
+
+        // Reaching the maximum value is treated like an overflow
+        let deltas = [n(250), n(4), n(0)];
+        let mut decoded = NonMaxU8::delta_decode(deltas.iter().copied(), n(1));
+        assert_eq!(decoded.next(), Some(Some(n(251))));
+        assert_eq!(decoded.next(), Some(None));
+        assert_eq!(decoded.next(), Some(None));
+        assert_eq!(decoded.next(), None);
+    }
+
+    #[test]
+    fn encoded_len() {
+        assert_eq!(NonMaxU32::ENCODED_LEN, 4);
+        assert_eq!(NonMaxI128::ENCODED_LEN, 16);
+    }
+
+    #[test]
+    fn u8_triple() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<NonMaxU8Triple>(), 4);
+        assert_eq!(size_of::<Option<NonMaxU8Triple>>(), 4);
+
+        let n = |value| NonMaxU8::new(value).unwrap();
+        for &(r, g, b) in [(0, 0, 0), (1, 2, 3), (254, 0, 254), (254, 254, 254)].iter() {
+            let triple = NonMaxU8Triple::new(n(r), n(g), n(b));
+            assert_eq!(triple.r().get(), r);
+            assert_eq!(triple.g().get(), g);
+            assert_eq!(triple.b().get(), b);
+        }
+    }
+
+    #[test]
+    fn checked_index() {
+        let mut values = [10, 20, 30];
+        let first = NonMaxUsize::ZERO;
+        let last = NonMaxUsize::new(2).unwrap();
+        let past_end = NonMaxUsize::new(3).unwrap();
+
+        assert_eq!(first.checked_index(&values), Some(&10));
+        assert_eq!(last.checked_index(&values), Some(&30));
+        assert_eq!(past_end.checked_index(&values), None);
+        assert_eq!(NonMaxUsize::MAX.checked_index(&values), None);
+
+        *last.checked_index_mut(&mut values).unwrap() = 35;
+        assert_eq!(values, [10, 20, 35]);
+        assert_eq!(past_end.checked_index_mut(&mut values), None);
+    }
+
+    #[test]
+    fn id_gen() {
+        let mut ids = IdGen::new();
+        assert_eq!(ids.next(), Some(NonMaxU32::ZERO));
+        assert_eq!(ids.next(), Some(NonMaxU32::ONE));
+        assert_eq!(ids.next(), NonMaxU32::new(2));
+
+        let mut ids = IdGen::starting_at(NonMaxU32::new(u32::MAX - 2).unwrap());
+        assert_eq!(ids.next(), NonMaxU32::new(u32::MAX - 2));
+        assert_eq!(ids.next(), Some(NonMaxU32::MAX));
+        assert_eq!(ids.next(), None);
+        assert_eq!(ids.next(), None);
+    }
+
+    #[test]
+    fn remaining() {
+        assert_eq!(NonMaxU32::MAX.remaining(), 0);
+        assert_eq!(NonMaxU32::ZERO.remaining(), u32::MAX - 1);
+        assert_eq!(NonMaxU8::new(250).unwrap().remaining(), 4);
+        assert_eq!(NonMaxU128::ONE.remaining(), u128::MAX - 2);
+    }
+
+    #[test]
+    fn write_radix() {
+        let mut buf = [0; NonMaxU32::max_radix_len(16)];
+        assert_eq!(buf.len(), 8);
+
+        let value = NonMaxU32::new(0xdead_beef).unwrap();
+        assert_eq!(value.write_radix(16, &mut buf), Ok("deadbeef"));
+        assert_eq!(NonMaxU32::MAX.write_radix(16, &mut buf), Ok("fffffffe"));
+        assert_eq!(
+            value.write_radix(16, &mut buf[..7]),
+            Err(BufferTooSmall(()))
+        );
+
+        let mut buf = [0; NonMaxI8::max_radix_len(16)];
+        assert_eq!(buf.len(), 3);
+        assert_eq!(NonMaxI8::MIN.write_radix(16, &mut buf), Ok("-80"));
+        assert_eq!(NonMaxI8::MAX.write_radix(16, &mut buf), Ok("7e"));
+        assert_eq!(
+            NonMaxI128::MIN.write_radix(2, &mut [0; 129]).map(str::len),
+            Ok(129)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_radix_invalid() {
+        let _ = NonMaxU8::ONE.write_radix(37, &mut [0; 8]);
+    }
+
+    #[test]
+    fn neighbors() {
+        let nm = |value| NonMaxU32::new(value).unwrap();
+        assert_eq!(NonMaxU32::ZERO.neighbors(), (None, Some(nm(1))));
+        assert_eq!(nm(10).neighbors(), (Some(nm(9)), Some(nm(11))));
+        assert_eq!(NonMaxU32::MAX.neighbors(), (Some(nm(u32::MAX - 2)), None));
+
+        assert_eq!(NonMaxI8::MIN.neighbors().0, None);
+        assert_eq!(NonMaxI8::ZERO.neighbors().0, NonMaxI8::new(-1));
+        assert_eq!(NonMaxI8::MAX.neighbors().1, None);
+    }
+
+    #[test]
+    fn new_branchless_exhaustive() {
+        for value in 0..=u8::MAX {
+            assert_eq!(NonMaxU8::new_branchless(value), NonMaxU8::new(value));
+        }
+        for value in i8::MIN..=i8::MAX {
+            assert_eq!(NonMaxI8::new_branchless(value), NonMaxI8::new(value));
+        }
+    }
+
+    #[test]
+    fn parse_detailed() {
+        assert_eq!(NonMaxU8::parse_detailed("255"), Err(ParseDetail::EqualsMax));
+        assert_eq!(NonMaxU8::parse_detailed("256"), Err(ParseDetail::Overflow));
+        assert_eq!(
+            NonMaxU8::parse_detailed("-1"),
+            Err(ParseDetail::InvalidDigit)
+        );
+        assert_eq!(NonMaxI8::parse_detailed("-129"), Err(ParseDetail::Overflow));
+        assert_eq!(
+            NonMaxI8::parse_detailed("-"),
+            Err(ParseDetail::InvalidDigit)
+        );
+        assert_eq!(NonMaxI8::parse_detailed("-128"), Ok(NonMaxI8::MIN));
+        assert_eq!(NonMaxI8::parse_detailed("127"), Err(ParseDetail::EqualsMax));
+    }
+
+    #[test]
+    fn checked_rem_exhaustive() {
+        for lhs in i8::MIN..i8::MAX {
+            for rhs in i8::MIN..i8::MAX {
+                let nonmax = NonMaxI8::new(lhs)
+                    .unwrap()
+                    .checked_rem(NonMaxI8::new(rhs).unwrap());
+                assert_eq!(nonmax.map(|value| value.get()), lhs.checked_rem(rhs));
+            }
+        }
+
+        assert_eq!(NonMaxI8::MIN.checked_rem(NonMaxI8::new(-1).unwrap()), None);
+        assert_eq!(NonMaxU8::MAX.checked_rem(NonMaxU8::ZERO), None);
+        assert_eq!(
+            NonMaxU8::MAX.checked_rem(NonMaxU8::new(100).unwrap()),
+            NonMaxU8::new(54)
+        );
+    }
+
+    #[test]
+    fn mask_index() {
+        const MASK: NonMaxU64 = match NonMaxU64::new(15) {
+            Some(mask) => mask,
+            None => NonMaxU64::ZERO,
+        };
+        const INDEX: usize = super::mask_index(0x1234, MASK);
+        assert_eq!(INDEX, 4);
+
+        assert_eq!(super::mask_index(0, MASK), 0);
+        assert_eq!(super::mask_index(u64::MAX, MASK), 15);
+        assert_eq!(super::mask_index(u64::MAX, NonMaxU64::ZERO), 0);
+    }
+
+    #[test]
+    fn gen_index() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<GenIndex>(), 8);
+        assert_eq!(size_of::<Option<GenIndex>>(), 8);
+
+        let index = NonMaxU32::new(7).unwrap();
+        let handle = GenIndex::new(index, 3);
+        assert_eq!(handle.index(), index);
+        assert_eq!(handle.generation(), 3);
+        assert_eq!(handle.to_u64(), 3 << 32 | 7);
+        assert_eq!(GenIndex::from_u64(handle.to_u64()), Some(handle));
+
+        let last = GenIndex::new(NonMaxU32::MAX, u32::MAX);
+        assert_eq!(GenIndex::from_u64(last.to_u64()), Some(last));
+        assert_eq!(GenIndex::from_u64(u64::from(u32::MAX)), None);
+        assert_eq!(GenIndex::from_u64(u64::MAX), None);
+    }
+
+    #[test]
+    fn checked_sum() {
+        let n = |value| NonMaxU8::new(value).unwrap();
+        assert_eq!(
+            NonMaxU8::checked_sum(&[n(100), n(100), n(54)]),
+            NonMaxU8::new(254)
+        );
+        assert_eq!(NonMaxU8::checked_sum(&[n(100), n(100), n(55)]), None);
+
+        let i = |value| NonMaxI8::new(value).unwrap();
+        assert_eq!(
+            NonMaxI8::checked_sum(&[i(-128), i(100), i(100)]),
+            NonMaxI8::new(72)
+        );
+        assert_eq!(NonMaxI8::checked_sum(&[i(-100), i(-29)]), None);
+    }
+
+    #[test]
+    fn from_ascii_digit() {
+        assert_eq!(NonMaxU8::from_ascii_digit(b'0'), Some(NonMaxU8::ZERO));
+        assert_eq!(NonMaxU8::from_ascii_digit(b'9'), NonMaxU8::new(9));
+        assert_eq!(NonMaxU8::from_ascii_digit(b'a'), None);
+        assert_eq!(NonMaxU8::from_ascii_digit(b'/'), None);
+        assert_eq!(NonMaxU8::from_ascii_digit(b':'), None);
+        assert_eq!(NonMaxU8::from_ascii_digit(0xff), None);
+    }
+
+    #[test]
+    fn float_new_get() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<Option<NonMaxF32>>(), 4);
+        assert_eq!(size_of::<Option<NonMaxF64>>(), 8);
+
+        assert_eq!(NonMaxF32::new(f32::MAX), None);
+        assert_eq!(NonMaxF64::new(f64::MAX), None);
+        assert_eq!(NonMaxF32::new(1.5).unwrap().get(), 1.5);
+        assert_eq!(NonMaxF32::new(-f32::MAX).unwrap().get(), -f32::MAX);
+        assert_eq!(NonMaxF64::new(f64::INFINITY).unwrap().get(), f64::INFINITY);
+        assert!(NonMaxF64::new(f64::NAN).unwrap().get().is_nan());
+    }
+
+    #[test]
+    fn float_try_from() {
+        use core::convert::TryFrom;
+
+        assert_eq!(NonMaxF32::try_from(f32::MAX), Err(TryFromFloatError(())));
+        assert_eq!(NonMaxF64::try_from(f64::MAX), Err(TryFromFloatError(())));
+        assert_eq!(NonMaxF32::try_from(1.5).map(NonMaxF32::get), Ok(1.5));
+        assert_eq!(
+            NonMaxF64::try_from(-f64::MAX).map(NonMaxF64::get),
+            Ok(-f64::MAX)
+        );
+    }
+
+    #[test]
+    fn float_total_order() {
+        let ordered = [
+            -f32::NAN,
+            f32::NEG_INFINITY,
+            -f32::MAX,
+            -1.0,
+            -0.0,
+            0.0,
+            f32::MIN_POSITIVE,
+            1.0,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        for pair in ordered.windows(2) {
+            let lower = NonMaxF32::new(pair[0]).unwrap();
+            let higher = NonMaxF32::new(pair[1]).unwrap();
+            assert!(lower < higher, "{:?} < {:?}", lower, higher);
+        }
+
+        let value = NonMaxF64::new(2.0).unwrap();
+        assert_eq!(value.cmp(&value), core::cmp::Ordering::Equal);
+        assert!(NonMaxF64::new(-2.0).unwrap() < NonMaxF64::new(-1.0).unwrap());
+    }
+
+    #[test]
+    fn iter_set_bits() {
+        let value = NonMaxU64::new(1 << 0 | 1 << 5 | 1 << 63).unwrap();
+        let mut bits = value.iter_set_bits();
+        assert_eq!(bits.next(), Some(0));
+        assert_eq!(bits.next(), Some(5));
+        assert_eq!(bits.next(), Some(63));
+        assert_eq!(bits.next(), None);
+
+        assert_eq!(NonMaxU64::ZERO.iter_set_bits().next(), None);
+        assert_eq!(NonMaxU64::MAX.iter_set_bits().count(), 63);
+        assert_eq!(NonMaxU8::MAX.iter_set_bits().next(), Some(1));
+    }
+
+    #[test]
+    fn percent_of() {
+        assert_eq!(NonMaxU8::percent_of(0, 10).get(), 0);
+        assert_eq!(NonMaxU8::percent_of(5, 10).get(), 50);
+        assert_eq!(NonMaxU8::percent_of(10, 10).get(), 100);
+        assert_eq!(NonMaxU8::percent_of(1, 3).get(), 33);
+        assert_eq!(NonMaxU8::percent_of(20, 10).get(), 100);
+        assert_eq!(NonMaxU8::percent_of(u64::MAX, u64::MAX).get(), 100);
+        assert_eq!(NonMaxU8::percent_of(5, 0), NonMaxU8::ZERO);
+    }
+
+    #[test]
+    fn table() {
+        const TABLE: [NonMaxU16; 3] = table![NonMaxU16; 1, 2, u16::MAX - 1];
+        assert_eq!(
+            TABLE,
+            [NonMaxU16::ONE, NonMaxU16::new(2).unwrap(), NonMaxU16::MAX]
+        );
+
+        let empty: [NonMaxI8; 0] = table![NonMaxI8;];
+        assert_eq!(empty.len(), 0);
+        assert_eq!(table![NonMaxI8; -128, 0,], [NonMaxI8::MIN, NonMaxI8::ZERO]);
+    }
+
+    #[test]
+    fn saturating_abs() {
+        assert_eq!(NonMaxI8::MIN.saturating_abs(), NonMaxI8::MAX);
+        assert_eq!(NonMaxI8::new(-127).unwrap().saturating_abs(), NonMaxI8::MAX);
+        assert_eq!(NonMaxI8::new(-126).unwrap().saturating_abs().get(), 126);
+        assert_eq!(NonMaxI8::new(-5).unwrap().saturating_abs().get(), 5);
+        assert_eq!(NonMaxI8::ZERO.saturating_abs(), NonMaxI8::ZERO);
+        assert_eq!(NonMaxI8::MAX.saturating_abs(), NonMaxI8::MAX);
+        assert_eq!(NonMaxI64::MIN.saturating_abs(), NonMaxI64::MAX);
+    }
+
+    #[test]
+    fn validated() {
+        let values = [1, 2, u32::MAX, 3];
+        let mut validated = NonMaxU32::validated(values.iter().copied());
+        assert_eq!(validated.next(), Some(Ok(NonMaxU32::ONE)));
+        assert_eq!(
+            validated.next(),
+            Some(NonMaxU32::new(2).ok_or(TryFromIntError(())))
+        );
+        assert_eq!(validated.next(), Some(Err(TryFromIntError(()))));
+        assert_eq!(
+            validated.next(),
+            Some(NonMaxU32::new(3).ok_or(TryFromIntError(())))
+        );
+        assert_eq!(validated.next(), None);
+
+        let first_err =
+            NonMaxU32::validated(values.iter().copied()).position(|value| value.is_err());
+        assert_eq!(first_err, Some(2));
+    }
+
+    #[test]
+    fn prev_multiple_of() {
+        let n = |value| NonMaxU32::new(value).unwrap();
+        assert_eq!(n(200).prev_multiple_of(n(64)), Some(n(192)));
+        assert_eq!(n(192).prev_multiple_of(n(64)), Some(n(192)));
+        assert_eq!(n(63).prev_multiple_of(n(64)), Some(NonMaxU32::ZERO));
+        assert_eq!(n(200).prev_multiple_of(NonMaxU32::ZERO), None);
+        assert_eq!(
+            NonMaxU32::MAX.prev_multiple_of(NonMaxU32::MAX),
+            Some(NonMaxU32::MAX)
+        );
+        assert_eq!(
+            NonMaxU8::MAX.prev_multiple_of(NonMaxU8::new(100).unwrap()),
+            NonMaxU8::new(200)
+        );
+    }
+}
+
+#[cfg(test)]
+mod convert {
+    use super::*;
+
+    #[test]
+    fn nonmax_u16_to_usize() {
+        let value = NonMaxU16::new(u16::MAX - 1).unwrap();
+        assert_eq!(NonMaxUsize::from(value).get(), (u16::MAX - 1) as usize);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn nonmax_u32_to_usize() {
+        let value = NonMaxU32::new(u32::MAX - 1).unwrap();
+        assert_eq!(NonMaxUsize::from(value).get(), (u32::MAX - 1) as usize);
+        assert_eq!(NonMaxUsize::from(NonMaxU32::ZERO), NonMaxUsize::ZERO);
+    }
+
+    #[test]
+    fn checked_from_primitive() {
+        assert_eq!(NonMaxU8::checked_from(254u32), NonMaxU8::new(254));
+        assert_eq!(NonMaxU8::checked_from(255u32), None);
+        assert_eq!(NonMaxU8::checked_from(256u32), None);
+        assert_eq!(NonMaxU8::checked_from(-1i32), None);
+        assert_eq!(NonMaxI16::checked_from(-1i64), NonMaxI16::new(-1));
+        assert_eq!(NonMaxU32::checked_from(u32::MAX), None);
+    }
+
+    #[test]
+    fn checked_from_nonmax() {
+        let large = NonMaxU32::new(300).unwrap();
+        assert_eq!(NonMaxU16::checked_from(large), NonMaxU16::new(300));
+        assert_eq!(NonMaxU8::checked_from(large), None);
+
+        let negative = NonMaxI32::new(-5).unwrap();
+        assert_eq!(NonMaxI8::checked_from(negative), NonMaxI8::new(-5));
+        assert_eq!(NonMaxU64::checked_from(negative), None);
+    }
+
+    #[test]
+    fn checked_from_generic() {
+        fn all_fit<T: CheckedFrom<u64>>(values: &[u64]) -> bool {
+            values.iter().all(|&value| T::checked_from(value).is_some())
+        }
+
+        assert!(all_fit::<NonMaxU8>(&[1, 2, 254]));
+        assert!(!all_fit::<NonMaxU8>(&[1, 2, 255]));
+        assert!(all_fit::<NonMaxU16>(&[1, 2, 255]));
+    }
+
+    #[test]
+    fn nonmax_usize_to_narrower() {
+        use core::convert::TryFrom;
+
+        let small = NonMaxUsize::new(200).unwrap();
+        assert_eq!(NonMaxU8::try_from(small), Ok(NonMaxU8::new(200).unwrap()));
+        assert_eq!(NonMaxU16::try_from(small), Ok(NonMaxU16::new(200).unwrap()));
+        assert_eq!(NonMaxU32::try_from(small), Ok(NonMaxU32::new(200).unwrap()));
+
+        // Fits in the primitive, but is the maximum value
+        let max = NonMaxUsize::new(u8::MAX as usize).unwrap();
+        NonMaxU8::try_from(max).unwrap_err();
+
+        let large = NonMaxUsize::new(u16::MAX as usize + 1).unwrap();
+        NonMaxU8::try_from(large).unwrap_err();
+        NonMaxU16::try_from(large).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn nonmax_usize_to_u32() {
+        use core::convert::TryFrom;
+
+        let max = NonMaxUsize::new(u32::MAX as usize).unwrap();
+        NonMaxU32::try_from(max).unwrap_err();
+
+        let large = NonMaxUsize::new(u32::MAX as usize + 1).unwrap();
+        NonMaxU32::try_from(large).unwrap_err();
+
+        let fits = NonMaxUsize::new(u32::MAX as usize - 1).unwrap();
+        assert_eq!(NonMaxU32::try_from(fits), Ok(NonMaxU32::MAX));
+    }
+
+    #[test]
+    fn as_i128() {
+        assert_eq!(NonMaxI8::MIN.as_i128(), -128);
+        assert_eq!(NonMaxI128::MIN.as_i128(), i128::MIN);
+        assert_eq!(NonMaxU8::MAX.as_i128(), 254);
+        assert_eq!(NonMaxU64::MAX.as_i128(), u64::MAX as i128 - 1);
+
+        const VALUE: i128 = NonMaxI32::ONE.as_i128();
+        assert_eq!(VALUE, 1);
+    }
+
+    #[test]
+    fn cast_sign() {
+        let unsigned = NonMaxU32::new(0x8000_0000).unwrap();
+        assert_eq!(unsigned.cast_signed(), NonMaxI32::new(i32::MIN));
+        let unsigned = NonMaxU32::new(i32::MAX as u32).unwrap();
+        assert_eq!(unsigned.cast_signed(), None);
+
+        let signed = NonMaxI32::new(-2).unwrap();
+        assert_eq!(signed.cast_unsigned(), NonMaxU32::new(u32::MAX - 1));
+        let signed = NonMaxI32::new(-1).unwrap();
+        assert_eq!(signed.cast_unsigned(), None);
+
+        assert_eq!(NonMaxU8::ONE.cast_signed(), Some(NonMaxI8::ONE));
+        assert_eq!(NonMaxI8::ONE.cast_unsigned(), Some(NonMaxU8::ONE));
+    }
+
+    #[test]
+    fn cast_sign_preserves_bits() {
+        for bits in 0..u8::MAX {
+            let unsigned = NonMaxU8::new(bits).unwrap();
+            match unsigned.cast_signed() {
+                Some(signed) => {
+                    assert_eq!(signed.get(), bits as i8);
+                    assert_eq!(signed.cast_unsigned(), Some(unsigned));
+                }
+                None => assert_eq!(bits as i8, i8::MAX),
+            }
+        }
+
+        // The value-preserving conversion differs for the same input
+        let unsigned = NonMaxU8::new(200).unwrap();
+        assert_eq!(unsigned.cast_signed(), NonMaxI8::new(-56));
+        assert_eq!(NonMaxI8::checked_from(unsigned), None);
+    }
+
+    #[test]
+    fn nibbles() {
+        let value = NonMaxU16::new(0x12ab).unwrap();
+        assert_eq!(value.to_nibbles(), [0x1, 0x2, 0xa, 0xb]);
+        assert_eq!(NonMaxU16::from_nibbles(value.to_nibbles()), Some(value));
+
+        for value in 0..u8::MAX {
+            let nonmax = NonMaxU8::new(value).unwrap();
+            assert_eq!(NonMaxU8::from_nibbles(nonmax.to_nibbles()), Some(nonmax));
+        }
+
+        assert_eq!(NonMaxU16::from_nibbles([0xf; 4]), None);
+        assert_eq!(NonMaxU16::from_nibbles([0x10, 0, 0, 0]), None);
+        assert_eq!(
+            NonMaxU32::MAX.to_nibbles(),
+            [0xf, 0xf, 0xf, 0xf, 0xf, 0xf, 0xf, 0xe]
+        );
+    }
+
+    #[test]
+    fn saturating_as() {
+        let large = NonMaxU32::new(1000).unwrap();
+        assert_eq!(large.saturating_as_u8(), NonMaxU8::MAX);
+        assert_eq!(large.saturating_as_u8().get(), 254);
+        assert_eq!(large.saturating_as_u16().get(), 1000);
+
+        let small = NonMaxU32::new(253).unwrap();
+        assert_eq!(small.saturating_as_u8().get(), 253);
+        let edge = NonMaxU32::new(254).unwrap();
+        assert_eq!(edge.saturating_as_u8().get(), 254);
+
+        assert_eq!(NonMaxU128::MAX.saturating_as_u64(), NonMaxU64::MAX);
+        assert_eq!(NonMaxU64::MAX.saturating_as_u32(), NonMaxU32::MAX);
+        assert_eq!(NonMaxU16::ONE.saturating_as_u8(), NonMaxU8::ONE);
+        assert_eq!(NonMaxUsize::MAX.saturating_as_u16(), NonMaxU16::MAX);
+    }
+
+    #[test]
+    fn try_from_ref() {
+        use core::convert::TryFrom;
+
+        let values = [0u32, 7, u32::MAX, 9];
+        let mut converted = values.iter().map(NonMaxU32::try_from);
+        assert_eq!(converted.next(), Some(Ok(NonMaxU32::ZERO)));
+        assert_eq!(converted.next(), Some(Ok(NonMaxU32::new(7).unwrap())));
+        assert_eq!(converted.next(), Some(Err(TryFromIntError(()))));
+        assert_eq!(converted.next(), Some(Ok(NonMaxU32::new(9).unwrap())));
+        assert_eq!(converted.next(), None);
+    }
+
+    #[test]
+    fn from_unit_interval() {
+        let max = NonMaxU16::new(100).unwrap();
+        assert_eq!(NonMaxU16::from_unit_interval(0.0, max), NonMaxU16::ZERO);
+        assert_eq!(NonMaxU16::from_unit_interval(1.0, max), max);
+        assert_eq!(NonMaxU16::from_unit_interval(0.5, max).get(), 50);
+        assert_eq!(NonMaxU16::from_unit_interval(0.333, max).get(), 33);
+        assert_eq!(NonMaxU16::from_unit_interval(0.335, max).get(), 34);
+
+        assert_eq!(NonMaxU16::from_unit_interval(-0.5, max), NonMaxU16::ZERO);
+        assert_eq!(NonMaxU16::from_unit_interval(1.5, max), max);
+        assert_eq!(NonMaxU16::from_unit_interval(f64::INFINITY, max), max);
+        assert_eq!(
+            NonMaxU16::from_unit_interval(f64::NEG_INFINITY, max),
+            NonMaxU16::ZERO
+        );
+        assert_eq!(
+            NonMaxU16::from_unit_interval(f64::NAN, max),
+            NonMaxU16::ZERO
+        );
+
+        let full = NonMaxU16::from_unit_interval(0.999_999_999, NonMaxU16::MAX);
+        assert_eq!(full, NonMaxU16::MAX);
+        let wide = NonMaxU64::from_unit_interval(0.5, NonMaxU64::MAX);
+        assert_eq!(wide.get(), 1 << 63);
+    }
+
+    #[test]
+    fn wrapping() {
+        use core::convert::TryFrom;
+        use core::num::Wrapping;
+
+        let counter = Wrapping(u32::MAX - 1) + Wrapping(1);
+        assert_eq!(NonMaxU32::try_from(counter), Err(TryFromIntError(())));
+
+        let counter = counter + Wrapping(6);
+        let index = NonMaxU32::try_from(counter).unwrap();
+        assert_eq!(index.get(), 5);
+        assert_eq!(Wrapping::from(index), Wrapping(5));
+        assert_eq!(Wrapping::<i8>::from(NonMaxI8::MIN), Wrapping(i8::MIN));
+    }
+
+    #[test]
+    fn saturating_from_i64() {
+        assert_eq!(NonMaxU32::saturating_from_i64(-1), NonMaxU32::ZERO);
+        assert_eq!(NonMaxU32::saturating_from_i64(i64::MIN), NonMaxU32::ZERO);
+        assert_eq!(NonMaxU32::saturating_from_i64(1234).get(), 1234);
+        assert_eq!(
+            NonMaxU32::saturating_from_i64(u32::MAX as i64 - 1),
+            NonMaxU32::MAX
+        );
+        assert_eq!(
+            NonMaxU32::saturating_from_i64(u32::MAX as i64),
+            NonMaxU32::MAX
+        );
+        assert_eq!(NonMaxU32::saturating_from_i64(i64::MAX), NonMaxU32::MAX);
+
+        assert_eq!(NonMaxU8::saturating_from_i64(300), NonMaxU8::MAX);
+        assert_eq!(
+            NonMaxU64::saturating_from_i64(i64::MAX).get(),
+            i64::MAX as u64
+        );
+        assert_eq!(
+            NonMaxU128::saturating_from_i64(i64::MAX).get(),
+            i64::MAX as u128
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")] // Vec
+    fn to_bytes_vec() {
+        let value = NonMaxU32::new(0x0102_0304).unwrap();
+        assert_eq!(value.to_bytes_vec(Endian::Big), vec![1, 2, 3, 4]);
+        assert_eq!(value.to_bytes_vec(Endian::Little), vec![4, 3, 2, 1]);
+        assert_eq!(
+            value.to_bytes_vec(Endian::Native),
+            0x0102_0304u32.to_ne_bytes().to_vec()
+        );
+        assert_eq!(
+            NonMaxU32::MAX.to_bytes_vec(Endian::Big),
+            vec![0xff, 0xff, 0xff, 0xfe]
+        );
+    }
+
+    #[test]
+    fn u16_parts() {
+        let value = NonMaxU32::new(0x1234_5678).unwrap();
+        assert_eq!(value.split_u16(), (0x1234, 0x5678));
+        assert_eq!(NonMaxU32::from_u16_parts(0x1234, 0x5678), Some(value));
+
+        assert_eq!(NonMaxU32::MAX.split_u16(), (0xffff, 0xfffe));
+        assert_eq!(
+            NonMaxU32::from_u16_parts(0xffff, 0xfffe),
+            Some(NonMaxU32::MAX)
+        );
+        assert_eq!(NonMaxU32::from_u16_parts(0xffff, 0xffff), None);
+        assert_eq!(NonMaxU32::from_u16_parts(0, 0), Some(NonMaxU32::ZERO));
+    }
+
+    #[test]
+    fn try_into_primitive() {
+        let max = NonMaxU64::new(u64::from(u32::MAX)).unwrap();
+        assert_eq!(max.try_into_u32(), Ok(u32::MAX));
+        let over = NonMaxU64::new(u64::from(u32::MAX) + 1).unwrap();
+        assert_eq!(over.try_into_u32(), Err(TryFromIntError(())));
+        assert_eq!(NonMaxU64::ZERO.try_into_u32(), Ok(0));
+
+        assert_eq!(NonMaxU16::new(255).unwrap().try_into_u8(), Ok(255));
+        assert_eq!(
+            NonMaxU16::new(256).unwrap().try_into_u8(),
+            Err(TryFromIntError(()))
+        );
+        assert_eq!(NonMaxU128::MAX.try_into_u64(), Err(TryFromIntError(())));
+        assert_eq!(NonMaxUsize::new(300).unwrap().try_into_u16(), Ok(300));
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        use core::convert::{TryFrom, TryInto};
+
+        let value: NonMaxU32 = 0x1234_5678u32.to_ne_bytes().try_into().unwrap();
+        assert_eq!(value.get(), 0x1234_5678);
+        assert_eq!(NonMaxU32::try_from([0xff; 4]), Err(TryFromIntError(())));
+        assert_eq!(NonMaxU32::try_from([0; 4]), Ok(NonMaxU32::ZERO));
+    }
 }